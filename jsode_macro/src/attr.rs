@@ -0,0 +1,261 @@
+use syn::{meta::ParseNestedMeta, LitStr};
+
+/// `#[jsode(rename_all = "...")]`, convert Rust's names into JSON keys
+#[derive(Clone, Copy)]
+pub enum RenameRule {
+    Camel,
+    Snake,
+    Kebab,
+}
+
+impl RenameRule {
+    fn parse(lit: &LitStr) -> syn::Result<Self> {
+        match lit.value().as_str() {
+            "camelCase" => Ok(Self::Camel),
+            "snake_case" => Ok(Self::Snake),
+            "kebab-case" => Ok(Self::Kebab),
+            other => Err(syn::Error::new(lit.span(), format!("unknown rename rule `{other}`, expect one of `camelCase`, `snake_case`, `kebab-case`"))),
+        }
+    }
+
+    /// apply on either a snake_case field or a PascalCase variant
+    pub fn apply(&self, name: &str) -> String {
+        let words = split_words(name);
+        match self {
+            Self::Camel => words.iter().enumerate()
+                .map(|(index, word)| if index == 0 { word.to_lowercase() } else { capitalize(word) })
+                .collect(),
+            Self::Snake => words.iter().map(|word| word.to_lowercase()).collect::<Vec<_>>().join("_"),
+            Self::Kebab => words.iter().map(|word| word.to_lowercase()).collect::<Vec<_>>().join("-"),
+        }
+    }
+}
+
+// `user_id` -> [user, id], `UserId` -> [User, Id]
+fn split_words(name: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut word = String::new();
+    let mut prev_lower = false;
+    for c in name.chars() {
+        if c == '_' {
+            words.extend((!word.is_empty()).then(|| std::mem::take(&mut word)));
+            prev_lower = false;
+            continue;
+        }
+        if c.is_uppercase() && prev_lower {
+            words.push(std::mem::take(&mut word));
+        }
+        prev_lower = c.is_lowercase() || c.is_ascii_digit();
+        word.push(c);
+    }
+    words.extend((!word.is_empty()).then_some(word));
+    words
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    chars.next()
+        .map(|first| first.to_uppercase().chain(chars.flat_map(char::to_lowercase)).collect())
+        .unwrap_or_default()
+}
+
+/// value of `default` / `default = "path"`
+pub enum DefaultValue {
+    Trait,
+    Path(syn::ExprPath),
+}
+
+impl DefaultValue {
+    /// expression creating the default value
+    pub fn to_expr(&self) -> proc_macro2::TokenStream {
+        match self {
+            Self::Trait => quote::quote!(::core::default::Default::default()),
+            Self::Path(path) => quote::quote!(#path()),
+        }
+    }
+}
+
+/// attributes on a struct or an enum
+#[derive(Default)]
+pub struct ContainerAttrs {
+    pub rename_all: Option<RenameRule>,
+    pub deny_unknown_fields: bool,
+    pub tag: Option<LitStr>,
+    pub content: Option<LitStr>,
+    pub untagged: bool,
+}
+
+/// attributes on a field
+#[derive(Default)]
+pub struct FieldAttrs {
+    pub rename: Option<LitStr>,
+    pub aliases: Vec<LitStr>,
+    pub default: Option<DefaultValue>,
+    pub skip: bool,
+    pub comment: Option<LitStr>,
+    pub flatten: bool,
+    pub path: Option<LitStr>,
+    /// module providing `deserialize` (and `serialize` for the Serialize derive)
+    pub with: Option<syn::ExprPath>,
+    pub deserialize_with: Option<syn::ExprPath>,
+    pub msg: Option<LitStr>,
+}
+
+/// attributes on an enum's variant
+#[derive(Default)]
+pub struct VariantAttrs {
+    pub rename: Option<LitStr>,
+    pub aliases: Vec<LitStr>,
+}
+
+impl ContainerAttrs {
+    pub fn parse(attrs: &[syn::Attribute]) -> syn::Result<Self> {
+        let mut result = Self::default();
+        parse_jsode_attrs(attrs, |meta| {
+            if meta.path.is_ident("rename_all") {
+                result.rename_all = Some(RenameRule::parse(&meta.value()?.parse()?)?);
+            } else if meta.path.is_ident("deny_unknown_fields") {
+                result.deny_unknown_fields = true;
+            } else if meta.path.is_ident("tag") {
+                result.tag = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("content") {
+                result.content = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("untagged") {
+                result.untagged = true;
+            } else {
+                return Err(unknown_attr(&meta, "rename_all, deny_unknown_fields, tag, content, untagged"));
+            }
+            Ok(())
+        })?;
+        Ok(result)
+    }
+}
+
+impl FieldAttrs {
+    pub fn parse(attrs: &[syn::Attribute]) -> syn::Result<Self> {
+        let mut result = Self {
+            rename: extract_legacy_meta(attrs, "prop"),
+            comment: extract_legacy_meta(attrs, "comment"),
+            msg: extract_legacy_meta(attrs, "msg"),
+            ..Default::default()
+        };
+        parse_jsode_attrs(attrs, |meta| {
+            if meta.path.is_ident("rename") {
+                result.rename = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("alias") {
+                result.aliases.push(meta.value()?.parse()?);
+            } else if meta.path.is_ident("default") {
+                result.default = Some(match meta.input.peek(syn::Token![=]) {
+                    true => DefaultValue::Path(meta.value()?.parse::<LitStr>()?.parse()?),
+                    false => DefaultValue::Trait,
+                });
+            } else if meta.path.is_ident("skip") {
+                result.skip = true;
+            } else if meta.path.is_ident("comment") {
+                result.comment = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("flatten") {
+                result.flatten = true;
+            } else if meta.path.is_ident("path") {
+                result.path = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("with") {
+                result.with = Some(meta.value()?.parse::<LitStr>()?.parse()?);
+            } else if meta.path.is_ident("deserialize_with") {
+                result.deserialize_with = Some(meta.value()?.parse::<LitStr>()?.parse()?);
+            } else if meta.path.is_ident("msg") {
+                result.msg = Some(meta.value()?.parse()?);
+            } else {
+                return Err(unknown_attr(&meta, "rename, alias, default, skip, comment, flatten, path, with, deserialize_with, msg"));
+            }
+            Ok(())
+        })?;
+        let span = attrs.iter().find(|attr| attr.path().is_ident("jsode")).map_or_else(proc_macro2::Span::call_site, syn::spanned::Spanned::span);
+        result.validate(span)?;
+        Ok(result)
+    }
+}
+
+impl FieldAttrs {
+    /// function reading the field's value from its `&JsonOutput`, `None` for the field's `Deserialize`
+    pub fn deserialize_fn(&self) -> Option<proc_macro2::TokenStream> {
+        match (&self.with, &self.deserialize_with) {
+            (Some(module), _) => Some(quote::quote!(#module::deserialize)),
+            (None, Some(func)) => Some(quote::quote!(#func)),
+            (None, None) => None,
+        }
+    }
+
+    // `flatten` and `path` decide by themselves where the value is read from
+    fn validate(&self, span: proc_macro2::Span) -> syn::Result<()> {
+        let conflict = |name: &str, with: &str| Err(syn::Error::new(span, format!("`{name}` cannot be used together with `{with}`")));
+        match self {
+            Self { flatten: true, path: Some(_), .. } => conflict("flatten", "path"),
+            Self { flatten: true, rename: Some(_), .. } => conflict("flatten", "rename"),
+            Self { flatten: true, default: Some(_), .. } => conflict("flatten", "default"),
+            Self { flatten: true, skip: true, .. } => conflict("flatten", "skip"),
+            Self { path: Some(_), rename: Some(_), .. } => conflict("path", "rename"),
+            Self { with: Some(_), deserialize_with: Some(_), .. } => conflict("with", "deserialize_with"),
+            Self { flatten: true, aliases, .. } if !aliases.is_empty() => conflict("flatten", "alias"),
+            Self { path: Some(_), aliases, .. } if !aliases.is_empty() => conflict("path", "alias"),
+            Self { path: Some(path), .. } if path.value().split('.').any(str::is_empty) => Err(syn::Error::new(path.span(), "empty segment in path")),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl VariantAttrs {
+    pub fn parse(attrs: &[syn::Attribute]) -> syn::Result<Self> {
+        let mut result = Self { rename: extract_legacy_meta(attrs, "prop"), ..Default::default() };
+        parse_jsode_attrs(attrs, |meta| {
+            if meta.path.is_ident("rename") {
+                result.rename = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("alias") {
+                result.aliases.push(meta.value()?.parse()?);
+            } else {
+                return Err(unknown_attr(&meta, "rename, alias"));
+            }
+            Ok(())
+        })?;
+        Ok(result)
+    }
+}
+
+// run `parser` on every item of every `#[jsode(...)]`
+fn parse_jsode_attrs(attrs: &[syn::Attribute], mut parser: impl FnMut(ParseNestedMeta) -> syn::Result<()>) -> syn::Result<()> {
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("jsode")) {
+        attr.parse_nested_meta(&mut parser)?;
+    }
+    Ok(())
+}
+
+fn unknown_attr(meta: &ParseNestedMeta, expected: &str) -> syn::Error {
+    let name = meta.path.get_ident().map(ToString::to_string).unwrap_or_default();
+    meta.error(format!("unknown jsode attribute `{name}`, expect one of {expected}"))
+}
+
+// the legacy `#[prop = "..."]`, `#[comment = "..."]` and `#[msg = "..."]`,
+// same as `#[jsode(rename = "...")]`, `#[jsode(comment = "...")]` and `#[jsode(msg = "...")]`
+fn extract_legacy_meta(attrs: &[syn::Attribute], name: &str) -> Option<LitStr> {
+    for syn::Attribute { meta, .. } in attrs {
+        if let syn::Meta::NameValue(syn::MetaNameValue { path, value: syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Str(lit_str), .. }), .. }) = meta {
+            if path.is_ident(name) {
+                return Some(lit_str.clone());
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RenameRule;
+
+    #[test]
+    fn rename_rule() {
+        assert_eq!("userId", RenameRule::Camel.apply("user_id"));
+        assert_eq!("moveTo", RenameRule::Camel.apply("MoveTo"));
+        assert_eq!("user_id", RenameRule::Snake.apply("user_id"));
+        assert_eq!("move_to", RenameRule::Snake.apply("MoveTo"));
+        assert_eq!("user-id2", RenameRule::Kebab.apply("user_id2"));
+        assert_eq!("move-to", RenameRule::Kebab.apply("MoveTo"));
+    }
+}
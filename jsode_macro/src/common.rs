@@ -1,9 +1,18 @@
 use quote::ToTokens;
-use syn::Data;
+use syn::{ext::IdentExt, Data};
 
-pub fn parse_body(body: Data) -> syn::Result<impl ToTokens> {
+use crate::attr::{ContainerAttrs, DefaultValue, FieldAttrs, RenameRule, VariantAttrs};
+
+pub fn parse_body(attrs: &[syn::Attribute], body: Data) -> syn::Result<StructType> {
+    let container = ContainerAttrs::parse(attrs)?;
+    if let Some(lit) = container.tag.as_ref().or(container.content.as_ref()) {
+        return Err(syn::Error::new(lit.span(), "`tag` and `content` only apply on Enum"));
+    }
+    if container.untagged {
+        return Err(syn::Error::new(proc_macro2::Span::call_site(), "`untagged` only apply on Enum"));
+    }
     match body {
-        Data::Struct(struct_body) => private::parse_struct_body(struct_body),
+        Data::Struct(struct_body) => private::parse_struct_body(&container, struct_body),
         Data::Enum(_) => Err(syn::Error::new(proc_macro2::Span::call_site(), "not apply on Enum")),
         Data::Union(_) => Err(syn::Error::new(proc_macro2::Span::call_site(), "not apply on Union")),
     }
@@ -11,19 +20,52 @@ pub fn parse_body(body: Data) -> syn::Result<impl ToTokens> {
 
 
 pub struct BodyProp {
-    attrs: Vec<syn::Attribute>,
+    attrs: FieldAttrs,
     name: Option<syn::Ident>,
     ty: syn::Type,
+    // JSON key of a named field, after `rename` or the container's `rename_all`
+    key: Option<syn::LitStr>,
 }
 
 impl BodyProp {
-    pub fn new(name: Option<syn::Ident>, ty: syn::Type, attrs: Vec<syn::Attribute>) -> Self {
-        Self { name, ty, attrs, }
+    pub fn new(field: syn::Field, rename_all: Option<RenameRule>) -> syn::Result<Self> {
+        let syn::Field { ident, ty, attrs, .. } = field;
+        let attrs = FieldAttrs::parse(&attrs)?;
+        // a field read from a path is reported missing under the whole path
+        let key = ident.as_ref().map(|ident| attrs.rename.clone()
+            .or_else(|| attrs.path.clone())
+            .unwrap_or_else(|| renamed(ident, rename_all)));
+        Ok(Self { name: ident, ty, attrs, key })
+    }
+
+    // whether the field is absent from JSON
+    fn is_skipped(&self) -> bool {
+        self.attrs.skip || matches!(check_type(&self.ty), FieldType::Phantom)
+    }
+}
+
+// type parameters named anywhere in the types of the fields read from or written to JSON
+fn used_params<'a>(props: impl Iterator<Item = &'a BodyProp>, params: &[syn::Ident]) -> Vec<syn::Ident> {
+    fn mentions(tokens: proc_macro2::TokenStream, param: &syn::Ident) -> bool {
+        tokens.into_iter().any(|it| match it {
+            proc_macro2::TokenTree::Ident(ident) => &ident == param,
+            proc_macro2::TokenTree::Group(group) => mentions(group.stream(), param),
+            _ => false,
+        })
     }
+    let types = props.filter(|it| !it.is_skipped()).map(|it| it.ty.to_token_stream()).collect::<Vec<_>>();
+    params.iter().filter(|param| types.iter().any(|ty| mentions(ty.clone(), param))).cloned().collect()
+}
+
+// name of a field or variant in JSON, `r#type` is written as `type`
+fn renamed(ident: &syn::Ident, rename_all: Option<RenameRule>) -> syn::LitStr {
+    let name = ident.unraw().to_string();
+    let name = rename_all.map(|rule| rule.apply(&name)).unwrap_or(name);
+    syn::LitStr::new(&name, ident.span())
 }
 
 /**
- * ```
+ * ```ignore
  * impl Deserialize for $struct_ident {
  *     pub fn parse(parer: &JsonParser, ast: &JsonType) -> Self {
  *         Self {
@@ -37,6 +79,7 @@ impl BodyProp {
  */
 pub struct StructBody {
     props: Vec<BodyProp>,
+    deny_unknown_fields: bool,
 }
 
 pub enum StructType {
@@ -44,9 +87,81 @@ pub enum StructType {
     Tuple(StructBody),
 }
 
-enum FieldType<'ty> {
+/// How an enum is represented, picked by the attributes on the enum
+pub enum Tagging {
+    /// `{ "Variant": content }`, or `"Variant"` for unit variant
+    External,
+    /// `#[jsode(tag = "type")]`, `{ "type": "Variant", ..fields }`
+    Internal(syn::LitStr),
+    /// `#[jsode(tag = "t", content = "c")]`, `{ "t": "Variant", "c": content }`
+    Adjacent(syn::LitStr, syn::LitStr),
+    /// `#[jsode(untagged)]`, the first variant which can be parsed from the content
+    Untagged,
+}
+
+pub enum VariantFields {
+    Unit,
+    Named(Vec<BodyProp>),
+    Unnamed(Vec<BodyProp>),
+}
+
+pub struct EnumVariant {
+    ident: syn::Ident,
+    // JSON name of the variant, after `rename` or the enum's `rename_all`
+    name: syn::LitStr,
+    aliases: Vec<syn::LitStr>,
+    fields: VariantFields,
+}
+
+pub struct EnumBody {
+    tagging: Tagging,
+    variants: Vec<EnumVariant>,
+}
+
+pub fn parse_enum_body(attrs: &[syn::Attribute], body: syn::DataEnum) -> syn::Result<EnumBody> {
+    let ContainerAttrs { rename_all, deny_unknown_fields, tag, content, untagged } = ContainerAttrs::parse(attrs)?;
+    if deny_unknown_fields {
+        return Err(syn::Error::new(proc_macro2::Span::call_site(), "`deny_unknown_fields` only apply on struct"));
+    }
+
+    let tagging = match (tag, content, untagged) {
+        (None, None, false) => Tagging::External,
+        (Some(tag), None, false) => Tagging::Internal(tag),
+        (Some(tag), Some(content), false) => Tagging::Adjacent(tag, content),
+        (None, None, true) => Tagging::Untagged,
+        (None, Some(content), false) => return Err(syn::Error::new(content.span(), "`content` requires `tag`")),
+        _ => return Err(syn::Error::new(proc_macro2::Span::call_site(), "`untagged` cannot be used together with `tag` or `content`")),
+    };
+
+    let mut variants = Vec::with_capacity(body.variants.len());
+    for syn::Variant { attrs, ident, fields, .. } in body.variants {
+        // `rename_all` of an enum renames its variants, not their fields
+        let fields = match fields {
+            syn::Fields::Unit => VariantFields::Unit,
+            syn::Fields::Named(syn::FieldsNamed { named, .. }) => VariantFields::Named(named.into_iter()
+                .map(|field| BodyProp::new(field, None))
+                .collect::<syn::Result<_>>()?),
+            syn::Fields::Unnamed(syn::FieldsUnnamed { unnamed, .. }) => VariantFields::Unnamed(unnamed.into_iter()
+                .map(|field| BodyProp::new(field, None))
+                .collect::<syn::Result<_>>()?),
+        };
+        // internally tagged content must be an object which also holds the tag
+        if let (Tagging::Internal(_), VariantFields::Unnamed(props)) = (&tagging, &fields) {
+            if props.len() != 1 {
+                return Err(syn::Error::new(ident.span(), "internally tagged enum does not support tuple variant"));
+            }
+        }
+        let VariantAttrs { rename, aliases } = VariantAttrs::parse(&attrs)?;
+        let name = rename.unwrap_or_else(|| renamed(&ident, rename_all));
+        variants.push(EnumVariant { ident, name, aliases, fields });
+    }
+
+    Ok(EnumBody { tagging, variants })
+}
+
+enum FieldType {
     Primitive,
-    Option(&'ty syn::Type),
+    Option,
     Phantom,
 }
 
@@ -69,9 +184,9 @@ fn check_type(ty: &syn::Type) -> FieldType {
             ident,
             arguments: PathArguments::AngleBracketed(AngleBracketedGenericArguments { args, .. }),
         }) = segments.first() {
-            if let (1, Some(GenericArgument::Type(inner_type))) = (args.len(), args.first()) {
+            if let (1, Some(GenericArgument::Type(_))) = (args.len(), args.first()) {
                 if ident == "Option" {
-                    return FieldType::Option(inner_type);
+                    return FieldType::Option;
                 } else if ident == "PhantomData" {
                     return FieldType::Phantom;
                 }
@@ -82,92 +197,465 @@ fn check_type(ty: &syn::Type) -> FieldType {
     FieldType::Primitive
 }
 
-impl ToTokens for StructType {
-    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
-        let result = match self {
-            Self::Struct(StructBody { props }) => {
-                let mut constructor = proc_macro2::TokenStream::new();
-                for BodyProp { name, ty, attrs } in props {
-                    let prop_index = private::extract_prop_meta(attrs)
-                        .or_else(|| Some(private::convert_to_list_str(name.as_ref().unwrap())));
-                    let item = match check_type(ty) {
-                        FieldType::Primitive => quote::quote! {
-                            #name: jsode::prelude::JsonPsr::parse_into::<#ty>(
-                                &jsode::prelude::JsonIdx::index(out, #prop_index)
-                                .ok_or_else(|| jsode::prelude::JsonError::custom(format!("key not found: `{}`", #prop_index), jsode::prelude::Span::default()))?
-                            )?,
+impl StructType {
+    /// type parameters of `params` which need a bound, those only used by skipped fields do not
+    pub fn bounded_params(&self, params: &[syn::Ident]) -> Vec<syn::Ident> {
+        let (Self::Struct(StructBody { props, .. }) | Self::Tuple(StructBody { props, .. })) = self;
+        used_params(props.iter(), params)
+    }
+
+    /// body of `Deserialize::parse`, named struct is read from an object,
+    /// tuple struct from an array, except newtype which is read from its inner value
+    pub fn to_deserialize_tokens(&self, ident: &syn::Ident) -> proc_macro2::TokenStream {
+        match self {
+            Self::Struct(StructBody { props, deny_unknown_fields }) => named_constructor(quote::quote!(Self), quote::quote!(stringify!(#ident)), props, *deny_unknown_fields, quote::quote!(out)),
+            Self::Tuple(StructBody { props, .. }) => unnamed_constructor(quote::quote!(Self), quote::quote!(stringify!(#ident)), props, quote::quote!(out)),
+        }
+    }
+}
+
+// value of a field absent from JSON, `Default::default()` unless `default = "path"`
+fn skipped_value(attrs: &FieldAttrs) -> proc_macro2::TokenStream {
+    attrs.default.as_ref().unwrap_or(&DefaultValue::Trait).to_expr()
+}
+
+// expression evaluating to `Result<Self>`, build `$path { .. }` from the object `out`
+fn named_constructor(
+    path: proc_macro2::TokenStream,
+    type_name: proc_macro2::TokenStream,
+    props: &[BodyProp],
+    deny_unknown_fields: bool,
+    out: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let mut constructor = proc_macro2::TokenStream::new();
+    let mut known_keys = Vec::with_capacity(props.len());
+    for prop @ BodyProp { name, ty, attrs, key } in props {
+        if prop.is_skipped() {
+            let value = skipped_value(attrs);
+            constructor.extend(quote::quote!(#name: #value,));
+            continue;
+        }
+        let parse = attrs.deserialize_fn().unwrap_or_else(|| quote::quote!(jsode::prelude::JsonPsr::parse_into::<#ty>));
+        let with_msg = with_msg(attrs);
+        // the flattened struct reads its own keys from the same object
+        if attrs.flatten {
+            constructor.extend(quote::quote! {
+                #name: #parse(out)#with_msg?,
+            });
+            continue;
+        }
+        let (lookup, found, located) = lookup_tokens(prop, &mut known_keys);
+        // expression evaluating to `Result<$ty>`
+        let item = match (check_type(ty), &attrs.default) {
+            (_, Some(default)) => {
+                let default = default.to_expr();
+                quote::quote! {
+                    #found
+                        .map(|x| #parse(&x)#located)
+                        .transpose()
+                        .map(|value| value.unwrap_or_else(|| #default))
+                }
+            },
+            // both `null` and missing key are `None`
+            (FieldType::Option, None) => quote::quote! {
+                #found
+                    .map(|x| #parse(&x)#located)
+                    .transpose()
+                    .map(Option::flatten)
+            },
+            // a missing key is located at the object which should hold it
+            _ => quote::quote! {
+                #found
+                    .ok_or_else(|| jsode::prelude::JsonError::missing_key(#key, out.get_span()))
+                    .and_then(|x| #parse(&x)#located)
+            },
+        };
+        constructor.extend(quote::quote! {
+            #name: {
+                #lookup
+                #item #with_msg?
+            },
+        });
+    }
+
+    let deny_unknown_keys = deny_unknown_fields.then(|| quote::quote! {
+        out.deny_unknown_keys(&[#(#known_keys),*])?;
+    });
+
+    quote::quote! {{
+        let out: &jsode::prelude::JsonOutput<'_, '__src> = #out;
+        if out.get_type_name() != "object" {
+            Err(jsode::prelude::JsonError::type_mismatch(#type_name, out.get_type_name(), out.get_span()))
+        } else {
+            #deny_unknown_keys
+            Ok(#path {
+                #constructor
+            })
+        }
+    }}
+}
+
+// `.map_err(..)` replacing the message of the field's error by `msg`,
+// a message given deeper (by a field of the field) is kept
+fn with_msg(attrs: &FieldAttrs) -> Option<proc_macro2::TokenStream> {
+    attrs.msg.as_ref().map(|msg| quote::quote! {
+        .map_err(|err| match err.message() {
+            Some(_) => err,
+            None => err.with_message(#msg),
+        })
+    })
+}
+
+// statements looking the value of a field up in the object `out`, the `Option<JsonOutput>` found,
+// then `.map_err(..)` locating an error of the value at its key(s).
+// Top-level keys the field reads are pushed into `known_keys`
+fn lookup_tokens(prop: &BodyProp, known_keys: &mut Vec<syn::LitStr>) -> (proc_macro2::TokenStream, proc_macro2::TokenStream, proc_macro2::TokenStream) {
+    let BodyProp { attrs, key, .. } = prop;
+    let Some(path) = &attrs.path else {
+        // the value of the key, or of the first alias found
+        let aliases = &attrs.aliases;
+        known_keys.extend(key.iter().chain(aliases).cloned());
+        return (proc_macro2::TokenStream::new(), quote::quote! {
+            jsode::prelude::JsonIdx::index(out, #key)
+                #(.or_else(|| jsode::prelude::JsonIdx::index(out, #aliases)))*
+        }, quote::quote! {
+            .map_err(|err| err.at_key(#key))
+        });
+    };
+
+    // `meta.items.0.id`, a segment of digits indexes an array
+    let path_value = path.value();
+    let segments = path_value.split('.').map(|segment| match segment.parse::<usize>() {
+        Ok(index) => (quote::quote!(#index), quote::quote!(at_index)),
+        Err(_) => (syn::LitStr::new(segment, path.span()).into_token_stream(), quote::quote!(at_key)),
+    }).collect::<Vec<_>>();
+    let (first, hops) = (&segments[0].0, segments[1..].iter().map(|(hop, _)| hop));
+    // the path is prepended from the deepest segment
+    let located = segments.iter().rev().map(|(hop, at)| quote::quote!(.#at(#hop)));
+    known_keys.extend(path_value.split('.').next().map(|segment| syn::LitStr::new(segment, path.span())));
+    (quote::quote! {
+        let hop = jsode::prelude::JsonIdx::index(out, #first);
+        #(let hop = hop.as_ref().and_then(|it| jsode::prelude::JsonIdx::index(it, #hops));)*
+    }, quote::quote!(hop), quote::quote! {
+        .map_err(|err| err #(#located)*)
+    })
+}
+
+// expression evaluating to `Result<Self>`, build `$path(..)` from the array `out`,
+// except newtype which is read from `out` itself
+fn unnamed_constructor(
+    path: proc_macro2::TokenStream,
+    type_name: proc_macro2::TokenStream,
+    props: &[BodyProp],
+    out: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let parse = |BodyProp { ty, attrs, .. }: &BodyProp| attrs.deserialize_fn()
+        .unwrap_or_else(|| quote::quote!(jsode::prelude::JsonPsr::parse_into::<#ty>));
+
+    if let [prop @ BodyProp { attrs: FieldAttrs { skip: false, .. }, .. }] = props {
+        let (parse, with_msg) = (parse(prop), with_msg(&prop.attrs));
+        return quote::quote! {
+            #parse(#out)#with_msg.map(#path)
+        };
+    }
+
+    let mut items = proc_macro2::TokenStream::new();
+    let mut index = 0usize;
+    for prop @ BodyProp { attrs, .. } in props {
+        if prop.is_skipped() {
+            items.extend(skipped_value(attrs));
+            items.extend(quote::quote!(,));
+            continue;
+        }
+        let (parse, with_msg) = (parse(prop), with_msg(attrs));
+        items.extend(quote::quote! {
+            #parse(&jsode::prelude::JsonIdx::index(out, #index).unwrap()).map_err(|err| err.at_index(#index))#with_msg?,
+        });
+        index += 1;
+    }
+
+    quote::quote! {{
+        let out: &jsode::prelude::JsonOutput<'_, '__src> = #out;
+        if out.get_type_name() != "array" {
+            Err(jsode::prelude::JsonError::type_mismatch(#type_name, out.get_type_name(), out.get_span()))
+        } else if out.len() != #index {
+            Err(jsode::prelude::JsonError::new(jsode::prelude::ErrorKind::InvalidLength { expected: #index, found: out.len() }, out.get_span()))
+        } else {
+            Ok(#path(#items))
+        }
+    }}
+}
+
+impl StructType {
+    /// methods of `Serialize`, named struct is written as an object,
+    /// tuple struct as an array, except newtype which is written as its inner value
+    pub fn to_serialize_tokens(&self) -> syn::Result<proc_macro2::TokenStream> {
+        let tokens = match self {
+            Self::Struct(StructBody { props, .. }) => {
+                let mut fields = proc_macro2::TokenStream::new();
+                for prop @ BodyProp { name, attrs, key, .. } in props {
+                    if prop.is_skipped() {
+                        continue;
+                    }
+                    if let Some(path) = &attrs.path {
+                        return Err(syn::Error::new(path.span(), "`path` is not supported by Serialize, add `skip` to only read this field"));
+                    }
+                    if let Some(comment) = &attrs.comment {
+                        fields.extend(quote::quote! {
+                            writer.comment(#comment)?;
+                        });
+                    }
+                    fields.extend(match (attrs.flatten, &attrs.with) {
+                        (true, _) => quote::quote!(jsode::prelude::Serialize::serialize_fields(&self.#name, writer)?;),
+                        (false, Some(module)) => quote::quote! {
+                            writer.key(#key)?;
+                            #module::serialize(&self.#name, writer)?;
+                        },
+                        (false, None) => quote::quote!(writer.field(#key, &self.#name)?;),
+                    });
+                }
+
+                quote::quote! {
+                    fn serialize<W: ::core::fmt::Write>(&self, writer: &mut jsode::prelude::JsonWriter<W>) -> ::core::fmt::Result {
+                        writer.begin_object()?;
+                        jsode::prelude::Serialize::serialize_fields(self, writer)?;
+                        writer.end_object()
+                    }
+
+                    fn serialize_fields<W: ::core::fmt::Write>(&self, writer: &mut jsode::prelude::JsonWriter<W>) -> ::core::fmt::Result {
+                        #fields
+                        Ok(())
+                    }
+                }
+            },
+            Self::Tuple(StructBody { props, .. }) if props.len() == 1 && !props[0].attrs.skip => {
+                let serialize = props[0].attrs.with.as_ref()
+                    .map_or_else(|| quote::quote!(jsode::prelude::Serialize::serialize), |module| quote::quote!(#module::serialize));
+                quote::quote! {
+                    fn serialize<W: ::core::fmt::Write>(&self, writer: &mut jsode::prelude::JsonWriter<W>) -> ::core::fmt::Result {
+                        #serialize(&self.0, writer)
+                    }
+                }
+            },
+            Self::Tuple(StructBody { props, .. }) => {
+                let mut items = proc_macro2::TokenStream::new();
+                for (index, prop) in props.iter().enumerate() {
+                    if prop.is_skipped() {
+                        continue;
+                    }
+                    if let Some(module) = &prop.attrs.with {
+                        return Err(syn::Error::new_spanned(module, "`with` is not supported by Serialize on the items of a tuple struct"));
+                    }
+                    let index = syn::Index::from(index);
+                    items.extend(quote::quote! {
+                        writer.element(&self.#index)?;
+                    });
+                }
+
+                quote::quote! {
+                    fn serialize<W: ::core::fmt::Write>(&self, writer: &mut jsode::prelude::JsonWriter<W>) -> ::core::fmt::Result {
+                        writer.begin_array()?;
+                        #items
+                        writer.end_array()
+                    }
+                }
+            },
+        };
+        Ok(tokens)
+    }
+}
+
+impl EnumVariant {
+    // expression evaluating to `Result<Self>`, build this variant from `content`
+    fn constructor(&self, enum_ident: &syn::Ident, content: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        let EnumVariant { ident, fields, .. } = self;
+        let type_name = quote::quote!(concat!(stringify!(#enum_ident), "::", stringify!(#ident)));
+        match fields {
+            VariantFields::Unit => quote::quote! {{
+                let out: &jsode::prelude::JsonOutput<'_, '__src> = #content;
+                if out.get_type_name() != "null" {
+                    Err(jsode::prelude::JsonError::type_mismatch(#type_name, out.get_type_name(), out.get_span()))
+                } else {
+                    Ok(Self::#ident)
+                }
+            }},
+            VariantFields::Named(props) => named_constructor(quote::quote!(Self::#ident), type_name, props, false, content),
+            VariantFields::Unnamed(props) => unnamed_constructor(quote::quote!(Self::#ident), type_name, props, content),
+        }
+    }
+
+    // same as `constructor`, an error is located at the key `at` holding the content
+    fn located_constructor(&self, enum_ident: &syn::Ident, content: proc_macro2::TokenStream, at: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        let constructor = self.constructor(enum_ident, content);
+        quote::quote! {{
+            let construct = || -> jsode::prelude::Result<Self> { #constructor };
+            construct().map_err(|err| err.at_key(#at))
+        }}
+    }
+}
+
+impl EnumBody {
+    /// type parameters of `params` which need a bound, those only used by skipped fields do not
+    pub fn bounded_params(&self, params: &[syn::Ident]) -> Vec<syn::Ident> {
+        let props = self.variants.iter().flat_map(|it| match &it.fields {
+            VariantFields::Unit => [].iter(),
+            VariantFields::Named(props) | VariantFields::Unnamed(props) => props.iter(),
+        });
+        used_params(props, params)
+    }
+
+    /// body of `Deserialize::parse` for an enum
+    pub fn to_deserialize_tokens(&self, ident: &syn::Ident) -> proc_macro2::TokenStream {
+        let names = self.variants.iter().map(|it| &it.name).collect::<Vec<_>>();
+        let variants = quote::quote! {
+            const VARIANTS: &[&str] = &[#(#names),*];
+        };
+        let not_object = quote::quote! {
+            if out.get_type_name() != "object" {
+                return Err(jsode::prelude::JsonError::type_mismatch(stringify!(#ident), out.get_type_name(), out.get_span()));
+            }
+        };
+        let read_tag = |tag: &syn::LitStr| quote::quote! {
+            let tag_out = jsode::prelude::JsonIdx::index(out, #tag)
+                .ok_or_else(|| jsode::prelude::JsonError::missing_key(#tag, out.get_span()))?;
+            let tag = jsode::prelude::JsonPsr::parse_into::<String>(&tag_out)?;
+        };
+
+        match &self.tagging {
+            Tagging::External => {
+                let unit_arms = self.variants.iter()
+                    .filter(|it| matches!(it.fields, VariantFields::Unit))
+                    .map(|EnumVariant { ident: variant, name, aliases, .. }| quote::quote!(#name #(| #aliases)* => Ok(Self::#variant),));
+                let arms = self.variants.iter().map(|variant| {
+                    let EnumVariant { name, aliases, .. } = variant;
+                    let constructor = variant.located_constructor(ident, quote::quote!(&content), quote::quote!(tag));
+                    quote::quote!(#name #(| #aliases)* => #constructor,)
+                });
+                quote::quote! {
+                    #variants
+                    match out.get_type_name() {
+                        "string" => {
+                            let tag = jsode::prelude::JsonPsr::parse_into::<String>(out)?;
+                            match tag.as_str() {
+                                #(#unit_arms)*
+                                _ => Err(jsode::prelude::JsonError::unknown_variant(tag, VARIANTS, out.get_span())),
+                            }
                         },
-                        FieldType::Option(inner_type) => quote::quote! {
-                            #name: jsode::prelude::JsonIdx::index(out, #prop_index)
-                                .map(|x| jsode::prelude::JsonPsr::parse_into::<#inner_type>(&x))
-                                .map_or(Ok(None), |x| x.map(Some))?,
+                        "object" => match (out.entries().next(), out.len()) {
+                            (Some((tag, content)), 1) => match tag {
+                                #(#arms)*
+                                _ => Err(jsode::prelude::JsonError::unknown_variant(tag, VARIANTS, out.get_span())),
+                            },
+                            _ => Err(jsode::prelude::JsonError::type_mismatch(stringify!(#ident), format!("object of {} keys", out.len()), out.get_span())),
                         },
-                        FieldType::Phantom => quote::quote! {
-                            #name: PhantomData::default(),
+                        other => Err(jsode::prelude::JsonError::type_mismatch(stringify!(#ident), other, out.get_span())),
+                    }
+                }
+            },
+            Tagging::Internal(tag) => {
+                let read_tag = read_tag(tag);
+                let arms = self.variants.iter().map(|variant| {
+                    let EnumVariant { ident: variant_ident, name, aliases, fields } = variant;
+                    let constructor = match fields {
+                        // the tag is the only content of a unit variant
+                        VariantFields::Unit => quote::quote!(Ok(Self::#variant_ident)),
+                        _ => variant.constructor(ident, quote::quote!(out)),
+                    };
+                    quote::quote!(#name #(| #aliases)* => #constructor,)
+                });
+                quote::quote! {
+                    #variants
+                    #not_object
+                    #read_tag
+                    match tag.as_str() {
+                        #(#arms)*
+                        _ => Err(jsode::prelude::JsonError::unknown_variant(tag, VARIANTS, tag_out.get_span())),
+                    }
+                }
+            },
+            Tagging::Adjacent(tag, content) => {
+                let read_tag = read_tag(tag);
+                let arms = self.variants.iter().map(|variant| {
+                    let EnumVariant { ident: variant_ident, name, aliases, fields } = variant;
+                    let constructor = match fields {
+                        // content of a unit variant can be omitted
+                        VariantFields::Unit => quote::quote!(Ok(Self::#variant_ident)),
+                        _ => {
+                            let constructor = variant.located_constructor(ident, quote::quote!(content), content.to_token_stream());
+                            quote::quote!({
+                                let content = content()?;
+                                #constructor
+                            })
                         },
                     };
-                    constructor.extend(item);
+                    quote::quote!(#name #(| #aliases)* => #constructor,)
+                });
+                quote::quote! {
+                    #variants
+                    #not_object
+                    #read_tag
+                    let content_out = jsode::prelude::JsonIdx::index(out, #content);
+                    let content = || content_out.as_ref()
+                        .ok_or_else(|| jsode::prelude::JsonError::missing_key(#content, out.get_span()));
+                    match tag.as_str() {
+                        #(#arms)*
+                        _ => Err(jsode::prelude::JsonError::unknown_variant(tag, VARIANTS, tag_out.get_span())),
+                    }
                 }
-
+            },
+            Tagging::Untagged => {
+                let attempts = self.variants.iter().map(|variant| {
+                    let constructor = variant.constructor(ident, quote::quote!(out));
+                    quote::quote! {
+                        let attempt = || -> jsode::prelude::Result<Self> { #constructor };
+                        if let Ok(value) = attempt() {
+                            return Ok(value);
+                        }
+                    }
+                });
                 quote::quote! {
-                    Ok(Self {
-                        #constructor
-                    })
+                    #(#attempts)*
+                    Err(jsode::prelude::JsonError::new(jsode::prelude::ErrorKind::UnmatchedVariant(stringify!(#ident)), out.get_span()))
                 }
             },
-            Self::Tuple(_) => quote::quote!(),
-        };
-        tokens.extend(result)
+        }
     }
 }
 
 mod private {
     use proc_macro2::Span;
-    use quote::ToTokens;
     use syn::DataStruct;
     use super::StructType;
 
-    use crate::common::{StructBody, BodyProp};
+    use crate::{attr::ContainerAttrs, common::{StructBody, BodyProp}};
 
 
-    pub fn parse_struct_body(DataStruct { fields, .. }: DataStruct) -> syn::Result<impl ToTokens> {
+    pub fn parse_struct_body(container: &ContainerAttrs, DataStruct { fields, .. }: DataStruct) -> syn::Result<StructType> {
         match fields {
-            syn::Fields::Named(named) => Ok(parse_struct_named(named)),
-            syn::Fields::Unnamed(unnamed) => Ok(parse_struct_unnamed(unnamed)),
+            syn::Fields::Named(named) => parse_struct_named(container, named),
+            syn::Fields::Unnamed(unnamed) => parse_struct_unnamed(container, unnamed),
             syn::Fields::Unit => Err(syn::Error::new(Span::call_site(), "not support on Unit struct")),
         }
     }
 
-    fn parse_struct_named(syn::FieldsNamed { named, .. }: syn::FieldsNamed) -> StructType {
+    fn parse_struct_named(container: &ContainerAttrs, syn::FieldsNamed { named, .. }: syn::FieldsNamed) -> syn::Result<StructType> {
         let props = named.into_iter()
-                .map(|syn::Field { ident, ty, attrs, .. }| BodyProp::new(ident, ty, attrs))
-                .collect::<Vec<_>>();
-        StructType::Struct(StructBody { props })
-    }
-
-    fn parse_struct_unnamed( syn::FieldsUnnamed { unnamed, .. }: syn::FieldsUnnamed) -> StructType {
-        let props = unnamed.into_iter()
-                .map(|syn::Field { ident, ty, attrs,.. }| BodyProp::new(ident, ty, attrs))
-                .collect::<Vec<_>>();
-        StructType::Tuple(StructBody { props })
-    }
-
-    pub fn extract_prop_meta(attrs: &Vec<syn::Attribute>) -> Option<syn::LitStr> {
-        for syn::Attribute { meta, .. } in attrs {
-            match meta {
-                syn::Meta::NameValue(syn::MetaNameValue { value: syn::Expr::Lit(syn::ExprLit { lit, .. }), .. }) => if let syn::Lit::Str(lit_str) = lit {
-                    return Some(lit_str.clone());
-                } else {
-                    continue;
-                },
-                _ => continue,
-            };
+                .map(|field| BodyProp::new(field, container.rename_all))
+                .collect::<syn::Result<Vec<_>>>()?;
+        // keys of a flattened struct are unknown to its parent
+        if container.deny_unknown_fields && props.iter().any(|prop| prop.attrs.flatten) {
+            return Err(syn::Error::new(Span::call_site(), "`deny_unknown_fields` cannot be used together with `flatten`"));
         }
-        None
+        Ok(StructType::Struct(StructBody { props, deny_unknown_fields: container.deny_unknown_fields }))
     }
 
-    pub fn convert_to_list_str(ident: &syn::Ident) -> syn::LitStr {
-        let ident_str = ident.to_string();
-        syn::LitStr::new(&ident_str, ident.span())
+    fn parse_struct_unnamed(container: &ContainerAttrs, syn::FieldsUnnamed { unnamed, .. }: syn::FieldsUnnamed) -> syn::Result<StructType> {
+        if container.deny_unknown_fields {
+            return Err(syn::Error::new(Span::call_site(), "`deny_unknown_fields` only apply on struct with named fields"));
+        }
+        let props = unnamed.into_iter()
+                .map(|field| BodyProp::new(field, None))
+                .collect::<syn::Result<Vec<_>>>()?;
+        Ok(StructType::Tuple(StructBody { props, deny_unknown_fields: false }))
     }
-}
\ No newline at end of file
+}
@@ -1,13 +1,38 @@
 use proc_macro::TokenStream;
 
 pub fn desrialize(tokens: TokenStream) -> syn::Result<proc_macro2::TokenStream> {
-    let syn::DeriveInput { attrs: _attrs, vis, ident, generics, data, } = syn::parse(tokens)?;
+    let syn::DeriveInput { attrs, vis: _vis, ident, generics, data, } = syn::parse(tokens)?;
 
-    let method_impl = crate::common::parse_body(data)?;
+    let params = generics.type_params().map(|it| it.ident.clone()).collect::<Vec<_>>();
+    let (method_impl, bounded) = match data {
+        syn::Data::Enum(body) => {
+            let body = crate::common::parse_enum_body(&attrs, body)?;
+            (body.to_deserialize_tokens(&ident), body.bounded_params(&params))
+        },
+        data => {
+            let body = crate::common::parse_body(&attrs, data)?;
+            (body.to_deserialize_tokens(&ident), body.bounded_params(&params))
+        },
+    };
+
+    // every type parameter of a field (but a skipped one) is read through its own `Deserialize`
+    let mut generics = generics;
+    let where_clause = generics.make_where_clause();
+    where_clause.predicates.extend(bounded.iter().map(|it| -> syn::WherePredicate { syn::parse_quote!(#it: jsode::prelude::Deserialize<'__src>) }));
+
+    // `'__src` (the lifetime of JSON source) outlives every lifetime of the struct,
+    // so borrowed fields such as `&'a str` can point directly into the source
+    let mut src_lifetime: syn::LifetimeParam = syn::parse_quote!('__src);
+    src_lifetime.bounds.extend(generics.lifetimes().map(|it| it.lifetime.clone()));
+    let mut src_generics = generics.clone();
+    src_generics.params.insert(0, syn::GenericParam::Lifetime(src_lifetime));
+
+    let (impl_generics, _, _) = src_generics.split_for_impl();
+    let (_, ty_generics, where_clause) = generics.split_for_impl();
 
     Ok(quote::quote! {
-        impl #generics jsode::prelude::Deserialize for #ident #generics {
-            fn parse(out: &jsode::prelude::JsonOutput<'_>) -> Result<Self> {
+        impl #impl_generics jsode::prelude::Deserialize<'__src> for #ident #ty_generics #where_clause {
+            fn parse(out: &jsode::prelude::JsonOutput<'_, '__src>) -> jsode::prelude::Result<Self> {
                 #method_impl
             }
         }
@@ -15,6 +40,20 @@ pub fn desrialize(tokens: TokenStream) -> syn::Result<proc_macro2::TokenStream>
 }
 
 pub fn serialize(tokens: TokenStream) -> syn::Result<proc_macro2::TokenStream> {
-    let input: syn::DeriveInput = syn::parse(tokens)?;
-    Ok(quote::quote!())
-}
\ No newline at end of file
+    let syn::DeriveInput { attrs, ident, generics, data, .. } = syn::parse(tokens)?;
+
+    let methods = crate::common::parse_body(&attrs, data)?.to_serialize_tokens()?;
+
+    // every type parameter is written through its own `Serialize`
+    let mut generics = generics;
+    let params = generics.type_params().map(|it| it.ident.clone()).collect::<Vec<_>>();
+    let where_clause = generics.make_where_clause();
+    where_clause.predicates.extend(params.iter().map(|it| -> syn::WherePredicate { syn::parse_quote!(#it: jsode::prelude::Serialize) }));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    Ok(quote::quote! {
+        impl #impl_generics jsode::prelude::Serialize for #ident #ty_generics #where_clause {
+            #methods
+        }
+    })
+}
@@ -1,11 +1,12 @@
 use proc_macro::TokenStream;
 use quote::{quote, ToTokens};
 
+mod attr;
 pub(crate) mod common;
 mod derive;
 
 
-#[proc_macro_derive(Deserialize, attributes(prop))]
+#[proc_macro_derive(Deserialize, attributes(jsode, prop, comment, msg))]
 pub fn deserialize(input: TokenStream) -> TokenStream {
     match derive::desrialize(input) {
         Ok(tokens) => tokens,
@@ -13,6 +14,14 @@ pub fn deserialize(input: TokenStream) -> TokenStream {
     }.into()
 }
 
+#[proc_macro_derive(Serialize, attributes(jsode, prop, comment, msg))]
+pub fn serialize(input: TokenStream) -> TokenStream {
+    match derive::serialize(input) {
+        Ok(tokens) => tokens,
+        Err(err) => err.to_compile_error(),
+    }.into()
+}
+
 #[proc_macro_attribute]
 pub fn reflection(_attr: TokenStream, src: TokenStream) -> TokenStream {
     let syn::ItemFn { sig, block, attrs, vis } = syn::parse(src).unwrap();
@@ -1,8 +1,4 @@
-use std::hash::Hasher;
-
-// https://github.com/rust-lang/rust/issues/104061
-// `std::hash::DefaultHasher::new` is not yet stable as a const fn
-// pub static HASHER: std::hash::DefaultHasher = std::hash::DefaultHasher::new();
+use std::hash::{BuildHasher, Hasher, RandomState};
 
 #[derive(PartialEq, Debug)]
 pub enum Holder<'a, T> {
@@ -58,8 +54,22 @@ impl <'a,T> From<&'a [T]> for Arrice<'a, T> {
     }
 }
 
-pub fn hash_str(input: &str) -> u64 {
-    let mut hasher = std::hash::DefaultHasher::new();
-    hasher.write(input.as_bytes());
-    hasher.finish()
+/// Hasher of object keys, randomly seeded for each parser
+/// so that colliding keys cannot be forged ahead to slow down the lookup.
+#[derive(Debug, Default)]
+pub struct KeyHasher(RandomState);
+
+impl KeyHasher {
+    pub fn hash_str(&self, input: &str) -> u64 {
+        let mut hasher = self.0.build_hasher();
+        hasher.write(input.as_bytes());
+        hasher.finish()
+    }
+}
+
+// the seed is not part of the parser's state
+impl PartialEq for KeyHasher {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
 }
\ No newline at end of file
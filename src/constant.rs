@@ -4,25 +4,66 @@ pub(crate) mod msg {
     pub const MISSING_DOUBLE_COLON: &str = "missing string's close character \"";
     pub const NOT_SUPPORT_TOKEN:    &str = "not support this token";
     pub const EMPTY_JSON:           &str = "json input is empty";
-    pub const INVALID_ARRAY:        &str = "not allow this token when parsing array";
-    // pub const DUPLICATE_KEY:        &str = "already exist key";
+    pub const DUPLICATE_KEY:        &str = "already exist key";
     pub const INVALID_ESCAPE:       &str = "the following escape string is not allow";
+    pub const UNPAIRED_SURROGATE:   &str = "surrogate must come in pair of high and low surrogate";
     pub const SOON_EOS:             &str = "No more state in stack, soon EOS";
+    pub const INVALID_NUMBER:       &str = "invalid number";
+    pub const INVALID_COMMENT:      &str = "invalid comment, must follow by another '/' (single-line comment) or '*' (multi-line comment)";
+    pub const INVALID_UTF8:         &str = "invalid UTF-8";
+    pub const UNEXPECTED_EOF:       &str = "unexpected end of input";
+    pub const TRAILING_DATA:        &str = "found trailing token after the root value";
+
+    // JSON5-only constructs, rejected in strict mode (RFC 8259)
+    pub const STRICT_SINGLE_QUOTE:   &str = "single-quoted string";
+    pub const STRICT_COMMENT:        &str = "comment";
+    pub const STRICT_IDENT:          &str = "unquoted identifier";
+    pub const STRICT_KEYWORD:        &str = "non-finite number";
+    pub const STRICT_WHITESPACE:     &str = "whitespace character";
+    pub const STRICT_ESCAPE:         &str = "escape sequence";
+    pub const STRICT_CONTROL_CHAR:   &str = "unescaped control character";
+    pub const STRICT_PLUS_SIGN:      &str = "leading '+'";
+    pub const STRICT_LEADING_DOT:    &str = "leading decimal point";
+    pub const STRICT_LEADING_ZERO:   &str = "leading zero";
+    pub const STRICT_HEXADECIMAL:    &str = "hexadecimal number";
+    pub const STRICT_TRAILING_COMMA: &str = "trailing comma";
+
+    // reasons of an invalid number
+    pub const EMPTY_FRACTION:   &str = "expect at least one digit after the decimal point";
+    pub const EMPTY_EXPONENT:   &str = "expect at least one digit in the exponent";
+    pub const EMPTY_HEXADECIMAL:&str = "expect at least one hexdigit after 0x";
+    pub const LONELY_SIGN:      &str = "'+' and '-' must be directly followed by a digit, '.', Infinity or NaN";
+    pub const LEADING_ZERO:     &str = "a number cannot start with a leading zero";
+    pub const UNEXPECTED_DIGIT: &str = "unexpected digit after the number";
+    pub const UNEXPECTED_CHAR:  &str = "unexpected character right after the number";
+
+    // reasons of an invalid path, see `JsonOutput::pointer` and `JsonOutput::get_path`
+    pub const POINTER_START:         &str = "a JSON pointer must be empty or start with '/'";
+    pub const POINTER_ESCAPE:        &str = "'~' must be followed by '0' or '1'";
+    pub const PATH_EMPTY_SEGMENT:    &str = "empty segment";
+    pub const PATH_MISSING_DOT:      &str = "expect '.' or '[' between two segments";
+    pub const PATH_INVALID_BRACKET:  &str = "expect an index or a quoted key inside brackets";
+    pub const PATH_UNCLOSED_BRACKET: &str = "missing closing ']'";
 }
 
 pub(crate) mod ascii {
-    pub const BACKSPACE: u8 = 0x08;
     pub const HORIZONTAL_TAB: u8 = 0x09;
     pub const LINE_FEED: u8 = 0x0A;
+    pub const VERTICAL_TAB: u8 = 0x0B;
     pub const FORM_FEED: u8 = 0x0C;
     pub const SPACE: u8 = 0x20;
     pub const CARRIAGE_RETURN: u8 = 0x0D;
-    pub const NON_BREAKING_SPACE: u8 = 0xA0;
-    // pub const PARAGRAPH_SEPARATOR: u16 = 0x2029;
-    // pub const LINE_SEPARATOR: u16 = 0x2028;
-    // pub const BYTE_ORDER_MARK: u16 = 0xFEFF;
 
     pub const ESCAPE: u8 = b'\\';
     pub const SINGLE_QUOTE: u8= b'\'';
     pub const DOUBLE_QUOTE: u8= b'\"';
+}
+
+pub(crate) mod unicode {
+    pub const NON_BREAKING_SPACE: char = '\u{00A0}';
+    pub const ZERO_WIDTH_NON_JOINER: char = '\u{200C}';
+    pub const ZERO_WIDTH_JOINER: char = '\u{200D}';
+    pub const LINE_SEPARATOR: char = '\u{2028}';
+    pub const PARAGRAPH_SEPARATOR: char = '\u{2029}';
+    pub const BYTE_ORDER_MARK: char = '\u{FEFF}';
 }
\ No newline at end of file
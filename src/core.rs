@@ -1,8 +1,6 @@
 use std::{collections::HashMap, fmt::Display, hash::Hash};
 
-use jsode_macro::reflection;
-
-use crate::{common::Arrice, error::JsonError, parser::JsonParser};
+use crate::{common::Arrice, error::{ErrorKind, JsonError}, option::DuplicateKey, parser::JsonParser};
 
 #[derive(PartialEq, PartialOrd, Debug)]
 pub enum JsonType {
@@ -32,9 +30,9 @@ impl JsonType {
 #[derive(PartialEq, PartialOrd, Debug, Clone)]
 pub enum StrType {
     Str(Span),
-    // \xXX (U+0000 through U+00FF)
+    // \xXX (U+0000 through U+00FF), span cover the whole escape
     Ascii(Span),
-    // \uXXXX (U+0000 through U+FFFF)
+    // \uXXXX (U+0000 through U+FFFF), span cover the whole escape
     Unicode(Span),
     // \X
     Escape(Span),
@@ -82,8 +80,10 @@ pub enum NumType {
     // -0xC0FFEE
     Hex(Heximal),
     // Infinity
+    // -Infinity (the span covers the sign)
     Infinity(Span),
     // NaN
+    // +NaN
     NaN(Span),
 }
 
@@ -161,20 +161,6 @@ impl Sign {
         }
     }
 
-    #[rustfmt::skip]
-    pub const fn compute_start_pos(&self, start: usize) -> usize {
-        // given a number ?123 (? is '+' or '-')
-        // if number has a prefix, move the position backward
-        match self {
-            // +123
-            Self::Plus  => start - 1,
-            // -123
-            Self::Minus => start - 1,
-            // 123
-            _           => start,
-        }
-    }
-
     pub const fn to_hexadecimal(&self, prefix: Span, suffix: Span) -> NumType {
         match self {
             Self::Plus => NumType::Hex(Heximal::Positive(prefix.expand_left(1), suffix)),
@@ -192,21 +178,27 @@ impl Sign {
     }
 
     pub const fn to_decimal(&self, int_span: Option<Span>, frac_span: Option<Span>, expo_span: Option<Span>) -> NumType {
-        let new_int_span = if let Some(integer_span) = int_span { Some(integer_span.expand_left(1)) } else { None };
+        // only a signed integer part covers its sign, `36.6` may start at the very first position
         match self {
-            Self::Plus => NumType::Decimal(Decimal::Positive(new_int_span, frac_span, expo_span)),
-            Self::Minus => NumType::Decimal(Decimal::Negative(new_int_span, frac_span, expo_span)),
-            _ => NumType::Decimal(Decimal::Positive(new_int_span, frac_span, expo_span)),
+            Self::Plus => NumType::Decimal(Decimal::Positive(Self::cover_sign(int_span), frac_span, expo_span)),
+            Self::Minus => NumType::Decimal(Decimal::Negative(Self::cover_sign(int_span), frac_span, expo_span)),
+            _ => NumType::Decimal(Decimal::Positive(int_span, frac_span, expo_span)),
         }
     }
+
+    #[inline]
+    const fn cover_sign(span: Option<Span>) -> Option<Span> {
+        if let Some(span) = span { Some(span.expand_left(1)) } else { None }
+    }
 }
 
 impl StrType {
     pub(crate) fn parse_str<'a>(&'a self, parser: &'a JsonParser<'a>) -> Result<&'a str> {
         match self {
             Self::Str(span) => Ok(parser.take_slice(Span::new(span.start, span.end))?),
-            Self::Ascii(span) => Ok(parser.take_slice(Span::new(span.start + 2, span.end))?),
-            Self::Unicode(span) => Ok(parser.take_slice(Span::new(span.start + 2, span.end))?),
+            // escape sequence as it is, use `StrType::code_unit` to decode them
+            Self::Ascii(span) => Ok(parser.take_slice(span.clone())?),
+            Self::Unicode(span) => Ok(parser.take_slice(span.clone())?),
             Self::Escape(span) => Ok(parser.take_slice(Span::new(span.start + 1, span.end))?),
             Self::Special(span) => {
                 let raw = parser.take_raw(span.clone());
@@ -217,6 +209,17 @@ impl StrType {
     }
 }
 
+impl StrType {
+    /// value of the hex digits in `\xXX` and `\uXXXX`, `None` for other kinds
+    pub(crate) fn code_unit(&self, parser: &JsonParser<'_>) -> Option<u32> {
+        let (Self::Ascii(span) | Self::Unicode(span)) = self else {
+            return None;
+        };
+        let digits = std::str::from_utf8(parser.take_raw(span.copied().shrink_left(2))).ok()?;
+        u32::from_str_radix(digits, 16).ok()
+    }
+}
+
 const fn map_special_char<'a>(c: u8) -> &'a str {
     match c {
         b'\'' => "\'",
@@ -248,7 +251,30 @@ pub enum Punct {
     Minus      ,
 }
 
-#[derive(Default, PartialEq, Eq, PartialOrd, Hash, Clone, Debug)]
+impl Punct {
+    #[rustfmt::skip]
+    pub const fn get_type_name(&self) -> &str {
+        match self {
+            Self::DoubleQuote => "'\"'",
+            Self::SingleQuote => "'\''",
+            Self::Comma       => "','",
+            Self::Colon       => "':'",
+            Self::OpenSquare  => "'['",
+            Self::CloseSquare => "']'",
+            Self::OpenCurly   => "'{'",
+            Self::CloseCurly  => "'}'",
+            Self::WhiteSpace  => "whitespace",
+            Self::Plus        => "'+'",
+            Self::Minus       => "'-'",
+        }
+    }
+}
+
+/// Byte range on the source, together with the 1-based line (`row`) and column (`col`) of `start`.
+///
+/// `row` and `col` are derived from `start`, so they take no part in comparison and hashing.
+/// Both are `0` when the location is unknown.
+#[derive(Default, Clone, Debug)]
 pub struct Span {
     pub start: usize,
     pub end: usize,
@@ -256,6 +282,27 @@ pub struct Span {
     pub row: usize,
 }
 
+impl PartialEq for Span {
+    fn eq(&self, other: &Self) -> bool {
+        self.start == other.start && self.end == other.end
+    }
+}
+
+impl Eq for Span {}
+
+impl PartialOrd for Span {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some((self.start, self.end).cmp(&(other.start, other.end)))
+    }
+}
+
+impl Hash for Span {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.start.hash(state);
+        self.end.hash(state);
+    }
+}
+
 impl Span {
     #[inline(always)]
     pub const fn new(start: usize, end: usize) -> Self {
@@ -267,6 +314,25 @@ impl Span {
         Self { start, end: start + counter, col: 0, row: 0 }
     }
 
+    // `Clone::clone` is not yet usable in const context
+    #[inline(always)]
+    pub const fn copied(&self) -> Self {
+        Self { start: self.start, end: self.end, col: self.col, row: self.row }
+    }
+
+    #[inline(always)]
+    pub const fn with_location(mut self, row: usize, col: usize) -> Self {
+        self.row = row;
+        self.col = col;
+        self
+    }
+
+    /// whether `row` and `col` were resolved by the tokenizer
+    #[inline(always)]
+    pub const fn has_location(&self) -> bool {
+        self.row > 0
+    }
+
     #[inline(always)]
     pub const fn gap(&self) -> usize {
         self.end - self.start
@@ -274,19 +340,23 @@ impl Span {
 
     #[inline]
     pub const fn extend(&self, other: Span) -> Self {
-        Span::new(self.start, other.end)
+        Span::new(self.start, other.end).with_location(self.row, self.col)
     }
 
+    // `collapse`, `shrink_left` and `expand_left` never cross a line,
+    // they only step over quotes and signs
     #[inline(always)]
     pub const fn collapse(mut self, size: usize) -> Self {
         self.start += size;
         self.end -= size;
+        if self.has_location() { self.col += size; }
         self
     }
 
     #[inline(always)]
     pub const fn shrink_left(mut self, size: usize) -> Self {
         self.start += size;
+        if self.has_location() { self.col += size; }
         self
     }
 
@@ -299,6 +369,7 @@ impl Span {
     #[inline(always)]
     pub const fn expand_left(mut self, size: usize) -> Self {
         self.start -= size;
+        if self.has_location() { self.col = if self.col > size { self.col - size } else { 1 }; }
         self
     }
 
@@ -313,7 +384,7 @@ impl Span {
 pub enum JsonToken {
     Punct(Punct, Span),
     Data(JsonType, Span),
-    Error(String, Span),
+    Error(ErrorKind, Span),
     Comment(Span),
 }
 
@@ -327,7 +398,7 @@ impl JsonToken {
     #[inline(always)]
     pub const fn boolean(value: bool, start: usize) -> Self { Self::Data(JsonType::Bool(value), Span::new(start, start + if value { 4 } else { 5 })) }
     #[inline(always)]
-    pub const fn null(at: usize) -> Self { Self::Data(JsonType::Null, Span::new(at, at + 1)) }
+    pub const fn null(at: usize) -> Self { Self::Data(JsonType::Null, Span::new(at, at + 4)) }
     #[inline(always)]
     pub const fn open_curly(at: usize) -> Self { Self::Punct(Punct::OpenCurly, Span::new(at, at + 1)) }
     #[inline(always)]
@@ -354,12 +425,12 @@ impl JsonToken {
     pub const fn comment(at: usize, end: usize) -> Self { Self::Comment(Span::new(at, end)) }
 
     #[inline(always)]
-    pub fn error(msg: impl Into<String>, start: usize, end: usize) -> Self { Self::Error(msg.into(), Span::new(start, end)) }
+    pub const fn error(kind: ErrorKind, start: usize, end: usize) -> Self { Self::Error(kind, Span::new(start, end)) }
 }
 
 impl From<JsonError> for JsonToken {
     fn from(value: JsonError) -> Self {
-        JsonToken::Error(value.to_string(), value.span)
+        JsonToken::Error(value.kind, value.span)
     }
 }
 
@@ -374,10 +445,20 @@ impl JsonToken {
 
     pub const fn get_span(&self) -> Span {
         match self {
-            Self::Data(_, span) => Span::new(span.start, span.end),
-            Self::Punct(_, span) => Span::new(span.start, span.end),
-            Self::Error(_, span) => Span::new(span.start, span.end),
-            Self::Comment(span) => Span::new(span.start, span.end),
+            Self::Data(_, span) => span.copied(),
+            Self::Punct(_, span) => span.copied(),
+            Self::Error(_, span) => span.copied(),
+            Self::Comment(span) => span.copied(),
+        }
+    }
+
+    /// short description of the token, used in error messages
+    pub const fn describe(&self) -> &str {
+        match self {
+            Self::Data(ty, _) => ty.get_type_name(),
+            Self::Punct(punct, _) => punct.get_type_name(),
+            Self::Error(_, _) => "invalid token",
+            Self::Comment(_) => "comment",
         }
     }
 }
@@ -404,80 +485,252 @@ impl JsonBlock {
         where T::Err: Display {
         let span = self.value.get_span();
         let slice = parser.take_slice(span.clone())?;
-        slice.parse::<T>().map_err(|err| JsonError::custom(format!("{err}"), span))
+        slice.parse::<T>().map_err(|err| JsonError::invalid_number(err.to_string(), span))
     }
 
     #[inline]
-    pub(crate) fn parse_type_span<T: std::str::FromStr>(&self, parser: &JsonParser<'_>, span: Span) -> Result<T>
-        where T::Err: Display {
-        let slice = parser.take_slice(span.clone())?;
-        slice.parse::<T>().map_err(|err| JsonError::custom(format!("{err}"), span))
-    }
-
-    #[inline]
-    pub fn to_slice<'a>(&'a self, parser: &'a JsonParser<'a>) -> Result<&str> {
+    pub fn to_slice<'src>(&self, parser: &JsonParser<'src>) -> Result<&'src str> {
         let span = self.value.get_span();
         parser.take_slice(span)
     }
 
-    pub const fn to_bytes<'a>(&'a self, parser: &'a JsonParser<'a>) -> &[u8] {
+    pub const fn to_bytes<'src>(&self, parser: &JsonParser<'src>) -> &'src [u8] {
         let span = self.value.get_span();
         parser.take_raw(span)
     }
 }
 
+/// A parsed value, `'out` borrows the AST while `'src` is the lifetime of the source,
+/// which every string slice returned from here (or deserialized as `&'src str`) points into.
 #[derive(PartialEq, Debug)]
-pub struct JsonOutput<'out> {
-    pub(crate) parser: &'out JsonParser<'out>,
+pub struct JsonOutput<'out, 'src> {
+    pub(crate) parser: &'out JsonParser<'src>,
     pub(crate) ast: Arrice<'out, JsonBlock>,
 }
 
-impl <'out> JsonOutput<'out> {
-    pub fn new(parser: &'out JsonParser<'out>, ast: impl Into<Arrice<'out, JsonBlock>>) -> Self {
+impl <'out, 'src> JsonOutput<'out, 'src> {
+    pub fn new(parser: &'out JsonParser<'src>, ast: impl Into<Arrice<'out, JsonBlock>>) -> Self {
         Self { parser, ast: ast.into(), }
     }
 
     #[inline]
-    #[reflection]
     pub(crate) fn parse_type<T: std::str::FromStr>(&self) -> Result<T>
         where T::Err: Display {
         self.ast.as_slice().first()
             .map(|it| it.parse_type(self.parser))
-            .ok_or(JsonError::custom(format!("[{__fn_ident}] Soon EOF"), Span::default()))?
+            .ok_or_else(|| JsonError::unexpected_eof("value", Span::default()))?
     }
 
     #[inline]
-    #[reflection]
-    pub(crate) fn parse_type_span<T: std::str::FromStr>(&self, span: Span) -> Result<T>
-        where T::Err: Display {
+    pub fn to_slice(&self) -> Result<&'src str> {
         self.ast.as_slice().first()
-            .map(|it| it.parse_type_span(self.parser, span))
-            .ok_or(JsonError::custom(format!("[{__fn_ident}] Soon EOF"), Span::default()))?
+            .map(|it| it.to_slice(self.parser))
+            .ok_or_else(|| JsonError::unexpected_eof("value", Span::default()))?
     }
 
     #[inline]
-    #[reflection]
-    pub fn to_slice(&self) -> Result<&str> {
-        self.ast.as_slice().first()
-            .map(|it| it.to_slice(self.parser))
-            .ok_or(JsonError::custom(format!("[{__fn_ident}] Soon EOF"), Span::default()))?
+    pub fn to_slice_span(&self, span: Span) -> Result<&'src str> {
+        self.parser.take_slice(span)
     }
 
+    /// JSON type of the value, such as `object`, `array`, `string` or `null`
     #[inline]
-    pub fn to_slice_span(&self, span: Span) -> Result<&str> {
-        self.parser.take_slice(span)
+    pub fn get_type_name(&self) -> &str {
+        self.ast.as_slice().first().map_or("nothing", |it| it.value.get_type_name())
+    }
+
+    #[inline]
+    pub fn get_span(&self) -> Span {
+        self.ast.as_slice().first().map(|it| it.value.get_span()).unwrap_or_default()
     }
 
-    pub fn to_bytes(&self) -> Result<&[u8]> {
+    pub fn to_bytes(&self) -> Result<&'src [u8]> {
         self.ast.as_slice().first()
             .map(|it| Ok(it.to_bytes(self.parser)))
-            .ok_or(JsonError::custom("msg", Span::default()))?
+            .ok_or_else(|| JsonError::unexpected_eof("value", Span::default()))?
+    }
+
+    // properties of the value, empty if it is not an object
+    fn props(&self) -> Option<&PropIndexes> {
+        match self.ast.as_slice().first() {
+            Some(JsonBlock { value: JsonValue::Object(props, _), .. }) => Some(props),
+            _ => None,
+        }
+    }
+
+    /// keys of an object in source order, nothing for other values,
+    /// a repeated key is yielded for each occurrence only with [`DuplicateKey::All`]
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.props().into_iter()
+            .flat_map(|props| props.iter(self.parser))
+            .map(|(key, _, _)| key)
+    }
+
+    /// key and value of each property of an object in source order, nothing for other values
+    pub fn entries(&self) -> impl Iterator<Item = (&str, JsonOutput<'_, 'src>)> {
+        self.props().into_iter()
+            .flat_map(|props| props.iter(self.parser))
+            .map(|(key, _, pos)| (key, JsonOutput::new(self.parser, &self.ast.as_slice()[pos..])))
+    }
+
+    /// number of properties of an object or items of an array, `0` for other values
+    pub fn len(&self) -> usize {
+        match self.ast.as_slice().first() {
+            Some(JsonBlock { value: JsonValue::Object(props, _), .. }) => props.len(),
+            Some(JsonBlock { value: JsonValue::Array(items, _), .. }) => items.len(),
+            _ => 0,
+        }
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// every value of property `key` in source order, see [`DuplicateKey::All`]
+    pub fn get_all(&self, key: &str) -> impl Iterator<Item = JsonOutput<'_, 'src>> {
+        self.props().map(|props| props.get_all(self.parser, key))
+            .unwrap_or_default()
+            .into_iter()
+            .map(|pos| JsonOutput::new(self.parser, &self.ast.as_slice()[pos..]))
+    }
+
+    /// whether the value is an object having property `key`
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.props().is_some_and(|props| props.get(self.parser, key).is_some())
+    }
+
+    /// fail with [`ErrorKind::UnknownKey`] at the first key of an object
+    /// which is not one of `expected`, used by `#[jsode(deny_unknown_fields)]`
+    pub fn deny_unknown_keys(&self, expected: &'static [&'static str]) -> Result<()> {
+        let unknown = self.props().into_iter()
+            .flat_map(|props| props.iter(self.parser))
+            .find_map(|(found, key, _)| (!expected.contains(&found)).then(|| JsonError::unknown_key(found, expected, key.clone())));
+        unknown.map_or(Ok(()), Err)
+    }
+}
+
+/// Properties of an object in source order, together with a lookup by hashed key.
+///
+/// Hashes are only used to narrow the search, keys are always compared against the source,
+/// so two different keys sharing the same hash never shadow each other.
+#[derive(PartialEq, Debug, Default)]
+pub struct PropIndexes {
+    // span of the key (without quotes), position of the value relative to the object
+    // and the previous property whose key has the same hash
+    props: Vec<(Span, usize, Option<usize>)>,
+    // hashed key -> index in `props` of the latest property having this hash
+    lookup: HashMap<u64, usize>,
+    // index in `props` -> decoded key, only for keys having escape sequences
+    decoded: HashMap<usize, Box<str>>,
+}
+
+impl PropIndexes {
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self { props: Vec::with_capacity(capacity), lookup: HashMap::with_capacity(capacity), decoded: HashMap::new() }
+    }
+
+    /// a repeated key is handled according to [`ParserOptions::duplicate_key`](crate::option::ParserOptions::duplicate_key),
+    /// `decoded` is the key without its escape sequences, if it has any
+    pub fn insert(&mut self, parser: &JsonParser<'_>, key: Span, decoded: Option<Box<str>>, pos: usize) -> Result<()> {
+        let raw = parser.take_slice(key.clone())?;
+        let key_str = decoded.as_deref().unwrap_or(raw);
+        let pushed = self.insert_hashed(parser, parser.hasher().hash_str(key_str), key_str, key, pos)?;
+        if let Some(decoded) = decoded.filter(|_| pushed) {
+            self.decoded.insert(self.props.len() - 1, decoded);
+        }
+        Ok(())
+    }
+
+    // whether a new property was pushed, rather than merged into a previous one
+    fn insert_hashed(&mut self, parser: &JsonParser<'_>, key_hashed: u64, key_str: &str, key: Span, pos: usize) -> Result<bool> {
+        match (self.find(parser, key_hashed, key_str), parser.options().duplicate_key()) {
+            (Some(index), DuplicateKey::Error) => Err(JsonError::new(
+                ErrorKind::DuplicateKey { key: key_str.to_string(), first: self.props[index].0.clone() },
+                key,
+            )),
+            (Some(_), DuplicateKey::First) => Ok(false),
+            // the repeated key keeps its first place in source order, but points to the latest value
+            (Some(index), DuplicateKey::Last) => {
+                self.props[index].1 = pos;
+                Ok(false)
+            },
+            (Some(_), DuplicateKey::All) | (None, _) => {
+                let chained = self.lookup.insert(key_hashed, self.props.len());
+                self.props.push((key, pos, chained));
+                Ok(true)
+            },
+        }
+    }
+
+    /// relative position of the value of property `key`
+    #[inline]
+    pub fn get(&self, parser: &JsonParser<'_>, key: &str) -> Option<usize> {
+        self.find(parser, parser.hasher().hash_str(key), key).map(|index| self.props[index].1)
+    }
+
+    /// relative positions of every value of property `key` in source order,
+    /// more than one only when parsed with [`DuplicateKey::All`]
+    pub fn get_all(&self, parser: &JsonParser<'_>, key: &str) -> Vec<usize> {
+        let mut positions = Vec::new();
+        let mut next = self.lookup.get(&parser.hasher().hash_str(key)).copied();
+        while let Some(index) = next {
+            let (_, pos, chained) = &self.props[index];
+            if self.key_at(parser, index) == Some(key) {
+                positions.push(*pos);
+            }
+            next = *chained;
+        }
+        positions.reverse();
+        positions
+    }
+
+    // walk through all properties having the same hash until the key text matches,
+    // the latest property comes first
+    fn find(&self, parser: &JsonParser<'_>, key_hashed: u64, key: &str) -> Option<usize> {
+        let mut next = self.lookup.get(&key_hashed).copied();
+        while let Some(index) = next {
+            if self.key_at(parser, index) == Some(key) {
+                return Some(index);
+            }
+            next = self.props[index].2;
+        }
+        None
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.props.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.props.is_empty()
+    }
+
+    /// key, key span and relative position of each property, in source order
+    #[inline]
+    pub fn iter<'a>(&'a self, parser: &'a JsonParser<'_>) -> impl Iterator<Item = (&'a str, &'a Span, usize)> {
+        (0..self.props.len()).filter_map(|index| {
+            let (span, pos, _) = &self.props[index];
+            Some((self.key_at(parser, index)?, span, *pos))
+        })
+    }
+
+    // the decoded key if it had escape sequences, its source text otherwise
+    #[inline]
+    fn key_at<'a>(&'a self, parser: &'a JsonParser<'_>, index: usize) -> Option<&'a str> {
+        match self.decoded.get(&index) {
+            Some(decoded) => Some(decoded),
+            None => parser.take_slice(self.props[index].0.clone()).ok(),
+        }
     }
 }
 
 #[derive(PartialEq, Debug)]
 pub enum JsonValue {
-    Object(HashMap<usize,usize>, Span),
+    Object(PropIndexes, Span),
     Array(Vec<usize>, Span),
     // given prop `year: 2024`
     // JsonType - type of value (Number in this example)
@@ -491,10 +744,10 @@ impl JsonValue {
     #[inline]
     pub const fn get_span(&self) -> Span {
         match self {
-            Self::Object(_, span) => Span::new(span.start, span.end),
-            Self::Array(_, span) => Span::new(span.start, span.end),
-            Self::Prop(_, span, _) => Span::new(span.start, span.end),
-            Self::Value(_, span) => Span::new(span.start, span.end),
+            Self::Object(_, span) => span.copied(),
+            Self::Array(_, span) => span.copied(),
+            Self::Prop(_, span, _) => span.copied(),
+            Self::Value(_, span) => span.copied(),
         }
     }
 
@@ -509,4 +762,27 @@ impl JsonValue {
     }
 }
 
-pub type Result<T> = core::result::Result<T, JsonError>;
\ No newline at end of file
+pub type Result<T> = core::result::Result<T, JsonError>;
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use crate::{core::{PropIndexes, Span}, parser::JsonParser};
+
+    #[test]
+    fn colliding_keys_do_not_shadow_each_other() {
+        // keys `a`, `b` and `c` are inserted with the same forged hash
+        let parser = JsonParser::new("a b c");
+        let mut props = PropIndexes::default();
+        props.insert_hashed(&parser, 7, "a", Span::new(0, 1), 1).unwrap();
+        props.insert_hashed(&parser, 7, "b", Span::new(2, 3), 2).unwrap();
+        props.insert_hashed(&parser, 7, "c", Span::new(4, 5), 3).unwrap();
+        props.insert_hashed(&parser, 7, "a", Span::new(0, 1), 4).unwrap();
+
+        assert_eq!(Some(4), props.find(&parser, 7, "a").map(|index| props.props[index].1));
+        assert_eq!(Some(2), props.find(&parser, 7, "b").map(|index| props.props[index].1));
+        assert_eq!(Some(3), props.find(&parser, 7, "c").map(|index| props.props[index].1));
+        assert_eq!(None   , props.find(&parser, 7, "d"));
+        assert_eq!(None   , props.find(&parser, 8, "a"));
+        assert_eq!(3, props.len());
+    }
+}
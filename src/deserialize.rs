@@ -1,98 +1,76 @@
-use crate::{core::{Decimal, Heximal, Integer, JsonBlock, JsonOutput, JsonType, JsonValue, NumType, StrType}, error::JsonError, parser::JsonParser, Span};
+use std::{
+    borrow::Cow,
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque},
+    fmt::Display,
+    hash::{BuildHasher, Hash},
+    marker::PhantomData,
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6},
+    num::{NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize},
+    path::PathBuf,
+    rc::Rc,
+    str::FromStr,
+    sync::Arc,
+};
 
-pub trait Deserialize: Sized {
-    fn parse(out: &JsonOutput<'_>) -> Result<Self, JsonError>;
-}
+use crate::{core::{JsonBlock, JsonOutput, JsonType, JsonValue, NumType, StrType}, error::{ErrorKind, JsonError}, number::parse_integer, parser::JsonParser, serialize::Hex, Span};
 
-macro_rules! impl_unsigned_deserialization {
-    ($type:ty) => {
-        impl Deserialize for $type {
-            fn parse(out: &JsonOutput<'_>) -> Result<Self, JsonError> {
-                match out.ast.as_slice().first().map(|it| &it.value) {
-                    // positive integer
-                    Some(JsonValue::Value(JsonType::Num(NumType::Integer(Integer::Positive(_,_))), int_span)) => out.parse_type_span::<$type>(int_span.clone()),
-                    Some(JsonValue::Prop(JsonType::Num(NumType::Integer(Integer::Positive(_,_))),int_span,_)) => out.parse_type_span::<$type>(int_span.clone()),
-                    // positive hexadecimal
-                    Some(JsonValue::Value(JsonType::Num(NumType::Hex(Heximal::Positive(_, suffix))), hex_span)) => out.to_slice_span(suffix.clone()).and_then(|slice| <$type>::from_str_radix(slice,16).map_err(|err| JsonError::custom(err.to_string(), hex_span.clone()))),
-                    Some(JsonValue::Prop(JsonType::Num(NumType::Hex(Heximal::Positive(_,suffix))),hex_span,_)) => out.to_slice_span(suffix.clone()).and_then(|slice| <$type>::from_str_radix(slice,16).map_err(|err| JsonError::custom(err.to_string(), hex_span.clone()))),
-                    // [error] negative integer
-                    Some(JsonValue::Value(JsonType::Num(NumType::Integer(Integer::Negative(_,_))), int_span)) => Err(JsonError::custom(format!("cannot convert negative integer to {}", stringify!($type)), int_span.clone())),
-                    Some(JsonValue::Prop(JsonType::Num(NumType::Integer(Integer::Negative(_,_))), int_span,_)) => Err(JsonError::custom(format!("cannot convert negative integer to {}", stringify!($type)), int_span.clone())),
-                    // [error] negative hexadecimal
-                    Some(JsonValue::Value(JsonType::Num(NumType::Hex(Heximal::Negative(_,_))), hex_span)) => Err(JsonError::custom(format!("cannot convert negative hexadecimal to {}", stringify!($type)), hex_span.clone())),
-                    Some(JsonValue::Prop(JsonType::Num(NumType::Hex(Heximal::Negative(_,_))),hex_span,_)) => Err(JsonError::custom(format!("cannot convert negative hexadecimal to {}", stringify!($type)), hex_span.clone())),
-                    Some(other_type) => Err(JsonError::custom(format!("cannot convert type {} to type {}", other_type.get_type_name(), stringify!($type)), other_type.get_span())),
-                    _ => Err(JsonError::custom("Soon EOF", Span::default()))
-                }
-            }
-        }
-    };
-    ($type:ty, $($types:ty),+) => {
-        impl_unsigned_deserialization!($type);
-        impl_unsigned_deserialization!($($types),+);
-    };
+/// Build a value from a parsed [`JsonOutput`].
+///
+/// `'src` is the lifetime of the source, so a value may borrow from it (see `&'src str`).
+/// Types owning all their data implement it for any `'src`:
+///
+/// ```
+/// use jsode::prelude::*;
+///
+/// struct Celsius(f64);
+///
+/// impl Deserialize<'_> for Celsius {
+///     fn parse(out: &JsonOutput<'_, '_>) -> Result<Self> {
+///         out.parse_into::<f64>().map(Celsius)
+///     }
+/// }
+///
+/// let mut src = JsonParser::new("{ temp: 36.6 }");
+/// assert_eq!(36.6, src.parse()?.index("temp").unwrap().parse_into::<Celsius>()?.0);
+/// # Ok::<(), JsonError>(())
+/// ```
+pub trait Deserialize<'src>: Sized {
+    fn parse(out: &JsonOutput<'_, 'src>) -> Result<Self, JsonError>;
 }
 
-macro_rules! impl_signed_deserialization {
-    ($type:ty) => {
-        impl Deserialize for $type {
-            fn parse(out: &JsonOutput<'_>) -> Result<Self, JsonError> {
-                match out.ast.as_slice().first().map(|it| &it.value) {
-                    // positive integer
-                    Some(JsonValue::Value(JsonType::Num(NumType::Integer(Integer::Positive(_,_))), int_span)) => out.parse_type_span::<$type>(int_span.clone()),
-                    Some(JsonValue::Prop(JsonType::Num(NumType::Integer(Integer::Positive(_,_))),int_span,_)) => out.parse_type_span::<$type>(int_span.clone()),
-                    // negative integer
-                    Some(JsonValue::Value(JsonType::Num(NumType::Integer(Integer::Negative(_,_))), int_span)) => out.parse_type_span::<$type>(int_span.clone()),
-                    Some(JsonValue::Prop(JsonType::Num(NumType::Integer(Integer::Negative(_,_))), int_span,_)) => out.parse_type_span::<$type>(int_span.clone()),
-                    // positive hexadecimal
-                    Some(JsonValue::Value(JsonType::Num(NumType::Hex(Heximal::Positive(_, suffix))), hex_span)) => out.to_slice_span(suffix.clone()).and_then(|slice| <$type>::from_str_radix(slice,16).map_err(|err| JsonError::custom(err.to_string(), hex_span.clone()))),
-                    Some(JsonValue::Prop(JsonType::Num(NumType::Hex(Heximal::Positive(_,suffix))),hex_span,_)) => out.to_slice_span(suffix.clone()).and_then(|slice| <$type>::from_str_radix(slice,16).map_err(|err| JsonError::custom(err.to_string(), hex_span.clone()))),
-                    // negative hexadecimal
-                    Some(JsonValue::Value(JsonType::Num(NumType::Hex(Heximal::Negative(_, suffix))), hex_span)) => out.to_slice_span(suffix.clone()).and_then(|slice| <$type>::from_str_radix(slice,16).map(|it| -it).map_err(|err| JsonError::custom(err.to_string(), hex_span.clone()))),
-                    Some(JsonValue::Prop(JsonType::Num(NumType::Hex(Heximal::Negative(_, suffix))),hex_span,_)) => out.to_slice_span(suffix.clone()).and_then(|slice| <$type>::from_str_radix(slice,16).map(|it| -it).map_err(|err| JsonError::custom(err.to_string(), hex_span.clone()))),
-                    // [error] other
-                    Some(other_type) => Err(JsonError::custom(format!("cannot convert type {} to type {}", other_type.get_type_name(), stringify!($type)), other_type.get_span())),
-                    _ => Err(JsonError::custom("Soon EOF", Span::default()))
-                }
+// integers are converted exactly, see `number::integral` for the rules
+macro_rules! impl_integer_deserialization {
+    ($signed:literal => $($type:ty),+) => {$(
+        impl <'src> Deserialize<'src> for $type {
+            fn parse(out: &JsonOutput<'_, 'src>) -> Result<Self, JsonError> {
+                parse_integer(out, stringify!($type), $signed)
             }
         }
-    };
-    ($type:ty, $($types:ty),+) => {
-        impl_signed_deserialization!($type);
-        impl_signed_deserialization!($($types),+);
-    };
+    )+};
 }
 
 macro_rules! impl_float_deserialization {
     ($type:ty) => {
-        impl Deserialize for $type {
-            fn parse(out: &JsonOutput<'_>) -> Result<Self, JsonError> {
+        impl <'src> Deserialize<'src> for $type {
+            fn parse(out: &JsonOutput<'_, 'src>) -> Result<Self, JsonError> {
                 match out.ast.as_slice().first().map(|it| &it.value) {
-                    // positive integer
-                    Some(JsonValue::Value(JsonType::Num(NumType::Integer(Integer::Positive(_,_))), int_span)) => out.parse_type_span::<$type>(int_span.clone()),
-                    Some(JsonValue::Prop(JsonType::Num(NumType::Integer(Integer::Positive(_,_))),int_span,_)) => out.parse_type_span::<$type>(int_span.clone()),
-                    // negative integer
-                    Some(JsonValue::Value(JsonType::Num(NumType::Integer(Integer::Negative(_,_))), int_span)) => out.parse_type_span::<$type>(int_span.clone()),
-                    Some(JsonValue::Prop(JsonType::Num(NumType::Integer(Integer::Negative(_,_))), int_span,_)) => out.parse_type_span::<$type>(int_span.clone()),
-                    // positive decimal
-                    Some(JsonValue::Value(JsonType::Num(NumType::Decimal(Decimal::Positive(_,_,_))), _)) => out.parse_type::<$type>(), 
-                    Some(JsonValue::Prop(JsonType::Num(NumType::Decimal(Decimal::Positive(_,_,_))),_,_)) => out.parse_type::<$type>(), 
-                    // negative decimal
-                    Some(JsonValue::Value(JsonType::Num(NumType::Decimal(Decimal::Negative(_,_,_))), _)) => out.parse_type::<$type>(),
-                    Some(JsonValue::Prop(JsonType::Num(NumType::Decimal(Decimal::Negative(_,_,_))),_,_)) => out.parse_type::<$type>(),
+                    // integer | decimal, including their sign and exponent
+                    Some(JsonValue::Value(JsonType::Num(NumType::Integer(_) | NumType::Decimal(_)), _)) => out.parse_type::<$type>(),
+                    Some(JsonValue::Prop(JsonType::Num(NumType::Integer(_) | NumType::Decimal(_)),_,_)) => out.parse_type::<$type>(),
+                    // hexadecimal
+                    Some(JsonValue::Value(JsonType::Num(NumType::Hex(_)), _) | JsonValue::Prop(JsonType::Num(NumType::Hex(_)),_,_)) => {
+                        let value = parse_integer::<i128>(out, stringify!($type), true)?;
+                        Ok(value as $type)
+                    },
                     // keywords: Infinity | NaN
-                    // not sure why this pattern matching did not worked as expectation, but run smoothy after moving into to the final block
-                    // Some(JsonValue::Value(JsonType::Num(NumType::Infinity(_)), _)) => Ok(<$type>::INFINITY),
-                    // Some(JsonValue::Value(JsonType::Num(NumType::NaN(_)), _)) => Ok(<$type>::NAN),
-                    // other
-                    Some(other_type) => match other_type {
-                        JsonValue::Value(JsonType::Num(NumType::Infinity(_)), _) => Ok(<$type>::INFINITY),
-                        JsonValue::Prop(JsonType::Num(NumType::Infinity(_)),_,_) => Ok(<$type>::INFINITY),
-                        JsonValue::Value(JsonType::Num(NumType::NaN(_)), _) => Ok(<$type>::NAN),
-                        JsonValue::Prop(JsonType::Num(NumType::NaN(_)),_,_) => Ok(<$type>::NAN),
-                        _ => Err(JsonError::custom(format!("cannot convert type {} to type {}", other_type.get_type_name(), stringify!($type)), other_type.get_span())),
+                    Some(JsonValue::Value(JsonType::Num(NumType::Infinity(span)), _) | JsonValue::Prop(JsonType::Num(NumType::Infinity(span)),_,_)) => match out.parser.take_raw(span.clone()).first() {
+                        Some(b'-') => Ok(<$type>::NEG_INFINITY),
+                        _ => Ok(<$type>::INFINITY),
                     },
-                    _ => Err(JsonError::custom("Soon EOF", Span::default()))
+                    Some(JsonValue::Value(JsonType::Num(NumType::NaN(_)), _) | JsonValue::Prop(JsonType::Num(NumType::NaN(_)),_,_)) => Ok(<$type>::NAN),
+                    // other
+                    Some(other_type) => Err(JsonError::type_mismatch(stringify!($type), other_type.get_type_name(), other_type.get_span())),
+                    _ => Err(JsonError::unexpected_eof("value", Span::default()))
                 }
             }
         }
@@ -103,118 +81,446 @@ macro_rules! impl_float_deserialization {
     };
 }
 
-impl_unsigned_deserialization!(u8, u16, u32, u64, usize);
-impl_signed_deserialization!(i8, i16, i32, i64, isize);
+impl_integer_deserialization!(false => u8, u16, u32, u64, u128, usize);
+impl_integer_deserialization!(true => i8, i16, i32, i64, i128, isize);
 impl_float_deserialization!(f32, f64);
 
-impl Deserialize for bool {
-    fn parse(out: &JsonOutput<'_>) -> Result<Self, JsonError> {
+impl <'src> Deserialize<'src> for bool {
+    fn parse(out: &JsonOutput<'_, 'src>) -> Result<Self, JsonError> {
         match out.ast.as_slice().first().map(|it| &it.value) {
             Some(JsonValue::Value(JsonType::Bool(value), _)) => Ok(*value),
             Some(JsonValue::Prop(JsonType::Bool(value),_,_)) => Ok(*value),
-            Some(other_type) => Err(JsonError::invalid_type(other_type.get_span(), "bool")),
-            None => Err(JsonError::custom("Soon EOF", Span::default())),
+            Some(other_type) => Err(JsonError::type_mismatch("bool", other_type.get_type_name(), other_type.get_span())),
+            None => Err(JsonError::unexpected_eof("value", Span::default())),
         }
     }
 }
 
-impl Deserialize for String {
-    fn parse(out: &JsonOutput<'_>) -> Result<Self, JsonError> {
+impl <'src> Deserialize<'src> for String {
+    fn parse(out: &JsonOutput<'_, 'src>) -> Result<Self, JsonError> {
         match out.ast.as_slice().first().map(|it| &it.value) {
             Some(JsonValue::Value(JsonType::Str(str_tokens),_)) => Ok(parse_str(out.parser, str_tokens)?),
             Some(JsonValue::Prop(JsonType::Str(str_tokens),_,_)) => Ok(parse_str(out.parser, str_tokens)?),
-            Some(other_type) => Err(JsonError::invalid_type(other_type.get_span(), "String")),
-            None => Err(JsonError::custom("Soon EOF", Span::default()))
+            Some(other_type) => Err(JsonError::type_mismatch("String", other_type.get_type_name(), other_type.get_span())),
+            None => Err(JsonError::unexpected_eof("value", Span::default()))
+        }
+    }
+}
+
+// zero copy, only a string without any escape sequence can be borrowed from the source
+impl <'a, 'src: 'a> Deserialize<'src> for &'a str {
+    fn parse(out: &JsonOutput<'_, 'src>) -> Result<Self, JsonError> {
+        match out.ast.as_slice().first().map(|it| &it.value) {
+            Some(JsonValue::Value(JsonType::Str(str_tokens), span) | JsonValue::Prop(JsonType::Str(str_tokens), span, _)) => borrow_str(out.parser, str_tokens)
+                .unwrap_or_else(|| Err(JsonError::type_mismatch("&str", "string with escape sequence", span.clone()))),
+            Some(other_type) => Err(JsonError::type_mismatch("&str", other_type.get_type_name(), other_type.get_span())),
+            None => Err(JsonError::unexpected_eof("value", Span::default()))
         }
     }
 }
 
-impl <T: Deserialize> Deserialize for Vec<T> {
-    fn parse(out: &JsonOutput<'_>) -> Result<Self, JsonError> {
-        let arr = out.ast.as_slice().first().map(|it| &it.value);
-        match arr {
-            // fixme: the `len` is totally incorrect in case array's item is an object or another array 
-            Some(JsonValue::Array(positions, _)) => parse_properties_to_vec(out.parser, out.ast.as_slice(), positions),
-            _ => Err(JsonError::custom("Soon EOF", Span::default()))
+// borrow when possible, allocate only when escape sequences must be decoded
+impl <'a, 'src: 'a> Deserialize<'src> for Cow<'a, str> {
+    fn parse(out: &JsonOutput<'_, 'src>) -> Result<Self, JsonError> {
+        match out.ast.as_slice().first().map(|it| &it.value) {
+            Some(JsonValue::Value(JsonType::Str(str_tokens),_) | JsonValue::Prop(JsonType::Str(str_tokens),_,_)) => match borrow_str(out.parser, str_tokens) {
+                Some(borrowed) => borrowed.map(Cow::Borrowed),
+                None => parse_str(out.parser, str_tokens).map(Cow::Owned),
+            },
+            Some(other_type) => Err(JsonError::type_mismatch("Cow<str>", other_type.get_type_name(), other_type.get_span())),
+            None => Err(JsonError::unexpected_eof("value", Span::default()))
         }
     }
 }
 
-// impl Deserialize for &[u8] {
-//     fn parse(out: &JsonOutput<'_>) -> Result<Self, JsonError> {
-//         match &out.ast {
-//             common::Holder::Owned(t) => Ok(out.parser.take_raw(t.get_span())), 
-//             common::Holder::Borrow(t) => Err(JsonError::empty_json(t.get_span())),
-//         }
-//     }
-// }
+impl <'src, T: Deserialize<'src>> Deserialize<'src> for Option<T> {
+    fn parse(out: &JsonOutput<'_, 'src>) -> Result<Self, JsonError> {
+        match out.ast.as_slice().first().map(|it| &it.value) {
+            Some(JsonValue::Value(JsonType::Null, _) | JsonValue::Prop(JsonType::Null,_,_)) => Ok(None),
+            _ => T::parse(out).map(Some),
+        }
+    }
+}
 
+impl <'src, T: Deserialize<'src>> Deserialize<'src> for Hex<T> {
+    fn parse(out: &JsonOutput<'_, 'src>) -> Result<Self, JsonError> {
+        T::parse(out).map(Hex)
+    }
+}
 
-#[inline(always)]
-fn parse_properties_to_vec<T: Deserialize>(
-    parser: &JsonParser<'_>,
-    props: &[JsonBlock],
-    positions: &[usize],
-) -> Result<Vec<T>, JsonError> {
-    positions.iter()
-        .map(|pos| JsonOutput::new(parser, &props[*pos..]).parse_into::<T>())
+impl <'src, T: Deserialize<'src>> Deserialize<'src> for Vec<T> {
+    fn parse(out: &JsonOutput<'_, 'src>) -> Result<Self, JsonError> {
+        parse_array_items(out)
+    }
+}
+
+impl <'src, T: Deserialize<'src>> Deserialize<'src> for VecDeque<T> {
+    fn parse(out: &JsonOutput<'_, 'src>) -> Result<Self, JsonError> {
+        parse_array_items(out)
+    }
+}
+
+impl <'src, T, S> Deserialize<'src> for HashSet<T, S>
+where
+    T: Deserialize<'src> + Eq + Hash,
+    S: BuildHasher + Default,
+{
+    fn parse(out: &JsonOutput<'_, 'src>) -> Result<Self, JsonError> {
+        parse_array_items(out)
+    }
+}
+
+impl <'src, T: Deserialize<'src> + Ord> Deserialize<'src> for BTreeSet<T> {
+    fn parse(out: &JsonOutput<'_, 'src>) -> Result<Self, JsonError> {
+        parse_array_items(out)
+    }
+}
+
+impl <'src, T: Deserialize<'src>, const N: usize> Deserialize<'src> for [T; N] {
+    fn parse(out: &JsonOutput<'_, 'src>) -> Result<Self, JsonError> {
+        let positions = array_positions(out, "array")?;
+        if positions.len() != N {
+            return Err(JsonError::new(ErrorKind::InvalidLength { expected: N, found: positions.len() }, out.get_span()));
+        }
+        let items = (0..N).map(|index| parse_array_item(out, positions, index)).collect::<Result<Vec<T>, _>>()?;
+        // the length is checked above
+        items.try_into().map_err(|_| JsonError::new(ErrorKind::InvalidLength { expected: N, found: positions.len() }, out.get_span()))
+    }
+}
+
+macro_rules! impl_tuple_deserialization {
+    ($len:literal => $($name:ident $index:literal),+) => {
+        impl <'src, $($name: Deserialize<'src>),+> Deserialize<'src> for ($($name,)+) {
+            fn parse(out: &JsonOutput<'_, 'src>) -> Result<Self, JsonError> {
+                let positions = array_positions(out, "tuple")?;
+                if positions.len() != $len {
+                    return Err(JsonError::new(ErrorKind::InvalidLength { expected: $len, found: positions.len() }, out.get_span()));
+                }
+                Ok(($(parse_array_item::<$name>(out, positions, $index)?,)+))
+            }
+        }
+    };
+}
+
+impl_tuple_deserialization!(1 => T0 0);
+impl_tuple_deserialization!(2 => T0 0, T1 1);
+impl_tuple_deserialization!(3 => T0 0, T1 1, T2 2);
+impl_tuple_deserialization!(4 => T0 0, T1 1, T2 2, T3 3);
+impl_tuple_deserialization!(5 => T0 0, T1 1, T2 2, T3 3, T4 4);
+impl_tuple_deserialization!(6 => T0 0, T1 1, T2 2, T3 3, T4 4, T5 5);
+impl_tuple_deserialization!(7 => T0 0, T1 1, T2 2, T3 3, T4 4, T5 5, T6 6);
+impl_tuple_deserialization!(8 => T0 0, T1 1, T2 2, T3 3, T4 4, T5 5, T6 6, T7 7);
+impl_tuple_deserialization!(9 => T0 0, T1 1, T2 2, T3 3, T4 4, T5 5, T6 6, T7 7, T8 8);
+impl_tuple_deserialization!(10 => T0 0, T1 1, T2 2, T3 3, T4 4, T5 5, T6 6, T7 7, T8 8, T9 9);
+impl_tuple_deserialization!(11 => T0 0, T1 1, T2 2, T3 3, T4 4, T5 5, T6 6, T7 7, T8 8, T9 9, T10 10);
+impl_tuple_deserialization!(12 => T0 0, T1 1, T2 2, T3 3, T4 4, T5 5, T6 6, T7 7, T8 8, T9 9, T10 10, T11 11);
+
+impl <'src> Deserialize<'src> for () {
+    fn parse(out: &JsonOutput<'_, 'src>) -> Result<Self, JsonError> {
+        match out.ast.as_slice().first().map(|it| &it.value) {
+            Some(JsonValue::Value(JsonType::Null, _) | JsonValue::Prop(JsonType::Null,_,_)) => Ok(()),
+            Some(other_type) => Err(JsonError::type_mismatch("()", other_type.get_type_name(), other_type.get_span())),
+            None => Err(JsonError::unexpected_eof("value", Span::default())),
+        }
+    }
+}
+
+impl <'src, T: Deserialize<'src>> Deserialize<'src> for Box<T> {
+    fn parse(out: &JsonOutput<'_, 'src>) -> Result<Self, JsonError> {
+        T::parse(out).map(Box::new)
+    }
+}
+
+impl <'src, T: Deserialize<'src>> Deserialize<'src> for Rc<T> {
+    fn parse(out: &JsonOutput<'_, 'src>) -> Result<Self, JsonError> {
+        T::parse(out).map(Rc::new)
+    }
+}
+
+impl <'src, T: Deserialize<'src>> Deserialize<'src> for Arc<T> {
+    fn parse(out: &JsonOutput<'_, 'src>) -> Result<Self, JsonError> {
+        T::parse(out).map(Arc::new)
+    }
+}
+
+impl <'src> Deserialize<'src> for char {
+    fn parse(out: &JsonOutput<'_, 'src>) -> Result<Self, JsonError> {
+        let text = parse_text(out, "char")?;
+        let mut chars = text.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Ok(c),
+            _ => Err(JsonError::new(ErrorKind::InvalidValue { ty: "char", reason: format!("expect a single character, found {}", text.chars().count()) }, out.get_span())),
+        }
+    }
+}
+
+macro_rules! impl_non_zero_deserialization {
+    ($($type:ty => $inner:ty),+) => {
+        $(
+            impl <'src> Deserialize<'src> for $type {
+                fn parse(out: &JsonOutput<'_, 'src>) -> Result<Self, JsonError> {
+                    <$type>::new(<$inner>::parse(out)?)
+                        .ok_or_else(|| JsonError::new(ErrorKind::InvalidValue { ty: stringify!($type), reason: "expect a non-zero number".into() }, out.get_span()))
+                }
+            }
+        )+
+    };
+}
+
+impl_non_zero_deserialization!(
+    NonZeroU8 => u8, NonZeroU16 => u16, NonZeroU32 => u32, NonZeroU64 => u64, NonZeroU128 => u128, NonZeroUsize => usize,
+    NonZeroI8 => i8, NonZeroI16 => i16, NonZeroI32 => i32, NonZeroI64 => i64, NonZeroI128 => i128, NonZeroIsize => isize
+);
+
+// types written as a string, converted via `FromStr`
+macro_rules! impl_from_str_deserialization {
+    ($($type:ty),+) => {
+        $(
+            impl <'src> Deserialize<'src> for $type {
+                fn parse(out: &JsonOutput<'_, 'src>) -> Result<Self, JsonError> {
+                    parse_from_str(out, stringify!($type))
+                }
+            }
+        )+
+    };
+}
+
+impl_from_str_deserialization!(IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6, PathBuf);
+
+impl <'src, K, V, S> Deserialize<'src> for HashMap<K, V, S>
+where
+    K: FromStr + Eq + Hash,
+    K::Err: Display,
+    V: Deserialize<'src>,
+    S: BuildHasher + Default,
+{
+    fn parse(out: &JsonOutput<'_, 'src>) -> Result<Self, JsonError> {
+        parse_object_to_map(out)
+    }
+}
+
+impl <'src, K, V> Deserialize<'src> for BTreeMap<K, V>
+where
+    K: FromStr + Ord,
+    K::Err: Display,
+    V: Deserialize<'src>,
+{
+    fn parse(out: &JsonOutput<'_, 'src>) -> Result<Self, JsonError> {
+        parse_object_to_map(out)
+    }
+}
+
+// positions of an array's items, relative to the array
+fn array_positions<'a>(out: &'a JsonOutput<'_, '_>, ty: &'static str) -> Result<&'a [usize], JsonError> {
+    match out.ast.as_slice().first().map(|it| &it.value) {
+        Some(JsonValue::Array(positions, _)) => Ok(positions),
+        Some(other_type) => Err(JsonError::type_mismatch(ty, other_type.get_type_name(), other_type.get_span())),
+        None => Err(JsonError::unexpected_eof("value", Span::default())),
+    }
+}
+
+#[inline]
+fn parse_array_item<'src, T: Deserialize<'src>>(out: &JsonOutput<'_, 'src>, positions: &[usize], index: usize) -> Result<T, JsonError> {
+    JsonOutput::new(out.parser, &out.ast.as_slice()[positions[index]..])
+        .parse_into::<T>()
+        .map_err(|err| err.at_index(index))
+}
+
+// collect an array into any collection, an error is located at the failing item
+fn parse_array_items<'src, T: Deserialize<'src>, C: FromIterator<T>>(out: &JsonOutput<'_, 'src>) -> Result<C, JsonError> {
+    let positions = array_positions(out, "array")?;
+    (0..positions.len())
+        .map(|index| parse_array_item(out, positions, index))
         .collect()
 }
 
-// currently heavy copy on source
-fn parse_str(
+// a string converted into `ty`, which is named by the type mismatch error
+fn parse_text<'src>(out: &JsonOutput<'_, 'src>, ty: &'static str) -> Result<Cow<'src, str>, JsonError> {
+    Cow::<str>::parse(out).map_err(|err| match err.kind {
+        ErrorKind::TypeMismatch { found, .. } => JsonError::type_mismatch(ty, found, err.span),
+        _ => err,
+    })
+}
+
+fn parse_from_str<T: FromStr>(out: &JsonOutput<'_, '_>, ty: &'static str) -> Result<T, JsonError>
+where
+    T::Err: Display,
+{
+    parse_text(out, ty)?
+        .parse::<T>()
+        .map_err(|err| JsonError::new(ErrorKind::InvalidValue { ty, reason: err.to_string() }, out.get_span()))
+}
+
+/// Collect an object into any map (or any other collection of key-value pairs),
+/// the key is converted via `FromStr` and every error is located at the failing key.
+///
+/// Useful to implement [`Deserialize`] for map types outside of `std`:
+///
+/// ```
+/// use std::collections::BTreeMap;
+/// use jsode::prelude::*;
+///
+/// struct Ordered(Vec<(String, u8)>);
+///
+/// impl Deserialize<'_> for Ordered {
+///     fn parse(out: &JsonOutput<'_, '_>) -> Result<Self> {
+///         jsode::parse_object_to_map(out).map(Ordered)
+///     }
+/// }
+///
+/// let mut src = JsonParser::new("{ b: 1, a: 2 }");
+/// let ordered = src.parse()?.parse_into::<Ordered>()?;
+/// assert_eq!(vec![("b".to_string(), 1), ("a".to_string(), 2)], ordered.0);
+/// # Ok::<(), JsonError>(())
+/// ```
+pub fn parse_object_to_map<'src, K, V, M>(out: &JsonOutput<'_, 'src>) -> Result<M, JsonError>
+where
+    K: FromStr,
+    K::Err: Display,
+    V: Deserialize<'src>,
+    M: FromIterator<(K, V)>,
+{
+    match out.ast.as_slice().first().map(|it| &it.value) {
+        Some(JsonValue::Object(props, _)) => props.iter(out.parser)
+            .map(|(key, key_span, pos)| {
+                let parsed_key = key.parse::<K>()
+                    .map_err(|err| JsonError::new(ErrorKind::InvalidKey { key: key.to_string(), reason: err.to_string() }, key_span.clone()).at_key(key))?;
+                let value = JsonOutput::new(out.parser, &out.ast.as_slice()[pos..]).parse_into::<V>()
+                    .map_err(|err| err.at_key(key))?;
+                Ok((parsed_key, value))
+            })
+            .collect(),
+        Some(other_type) => Err(JsonError::type_mismatch("map", other_type.get_type_name(), other_type.get_span())),
+        None => Err(JsonError::unexpected_eof("value", Span::default())),
+    }
+}
+
+// `None` if the string contains any escape sequence, which must be decoded into a new `String`
+#[inline]
+pub(crate) fn borrow_str<'src>(parser: &JsonParser<'src>, tokens: &[StrType]) -> Option<Result<&'src str, JsonError>> {
+    match tokens {
+        [] => Some(Ok("")),
+        [StrType::Str(span)] => Some(parser.take_slice(span.clone())),
+        _ => None,
+    }
+}
+
+// copy on source, prefer `borrow_str` when the string has no escape sequence
+pub(crate) fn parse_str(
     parser: &JsonParser<'_>,
-    tokens: &Vec<StrType>,
+    tokens: &[StrType],
 ) -> Result<String, JsonError> {
-    let mut result = Vec::<&str>::with_capacity(tokens.len());
-
-    for item in tokens {
-        let token = item.parse_str(parser)?;
-        result.push(token);
-    }
-
-    Ok(result.join(""))
-}
-
-// WARN: introduce memory leak because we haven't dealloc slice value after use
-// SOLUTION: impl Drop trait that provide manual memory management
-#[cfg(feature = "unstable")]
-impl <T: Deserialize> Deserialize for &[T] {
-    fn parse(out: &JsonOutput<'_>) -> Result<Self, JsonError> {
-        match &out.ast {
-            common::Holder::Owned(JsonValue::Array(JsonArray { properties: props, .. })) => {
-                let res = props.iter()
-                    .map(|prop| JsonOutput::new(out.parser, &prop.value).parse_into::<T>())
-                    .collect::<Result<Vec<T>, JsonError>>()?;
-                let ptr = res.as_ptr();
-                let len = res.len();
-                mem::forget(res); // avoid `res` being dropped by rust compiler
-                Ok(unsafe { std::slice::from_raw_parts(ptr, len) })
-            },
-            common::Holder::Borrow(JsonValue::Array(JsonArray { properties: props, .. })) => {
-                let res = props.iter()
-                    .map(|prop| JsonOutput::new(out.parser, &prop.value).parse_into::<T>())
-                    .collect::<Result<Vec<T>, JsonError>>()?;
-                let ptr = res.as_ptr();
-                let len = res.len();
-                mem::forget(res);
-                Ok(unsafe { std::slice::from_raw_parts(ptr, len) })
+    let mut result = String::with_capacity(tokens.len());
+    let mut tokens = tokens.iter().peekable();
+
+    while let Some(item) = tokens.next() {
+        let (StrType::Ascii(span) | StrType::Unicode(span)) = item else {
+            result.push_str(item.parse_str(parser)?);
+            continue;
+        };
+        let unpaired = || JsonError::new(ErrorKind::UnpairedSurrogate(String::from_utf8_lossy(parser.take_raw(span.clone())).into_owned()), span.clone());
+        let code = match item.code_unit(parser).unwrap_or_default() {
+            // high surrogate must be followed immediately by a low surrogate, `\ud83d\ude00` (😀)
+            high @ 0xD800..=0xDBFF => match tokens.peek().and_then(|next| next.code_unit(parser).filter(|_| matches!(next, StrType::Unicode(_)))) {
+                Some(low @ 0xDC00..=0xDFFF) => {
+                    tokens.next();
+                    0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00)
+                },
+                _ => return Err(unpaired()),
             },
-            common::Holder::Owned(other_type) => Err(JsonError::invalid_array(other_type.get_span())),
-            common::Holder::Borrow(other_type) => Err(JsonError::invalid_array(other_type.get_span())),
+            0xDC00..=0xDFFF => return Err(unpaired()),
+            code => code,
+        };
+        // every code point outside of surrogate range is a valid char
+        result.push(char::from_u32(code).ok_or_else(unpaired)?);
+    }
+
+    Ok(result)
+}
+
+/// Slice-like access to an array, items are deserialized lazily on access.
+///
+/// It borrows the AST of [`JsonOutput`], so nothing is copied until an item is requested.
+///
+/// ```
+/// use jsode::prelude::*;
+///
+/// let mut src = JsonParser::new("{ ports: [80, 443, 'oops'] }");
+/// let out = src.parse()?;
+/// let ports = out.index("ports").unwrap();
+/// let view = ports.as_array::<u16>()?;
+///
+/// assert_eq!(3, view.len());
+/// assert_eq!(Some(Ok(443)), view.get(1));
+/// assert!(view.get(2).is_some_and(|it| it.is_err()));
+/// assert_eq!(None, view.get(3));
+/// # Ok::<(), JsonError>(())
+/// ```
+pub struct JsonArrayView<'out, 'src, T> {
+    parser: &'out JsonParser<'src>,
+    // the array block followed by all of its descendants
+    ast: &'out [JsonBlock],
+    positions: &'out [usize],
+    _phantom: PhantomData<fn() -> T>,
+}
+
+impl <'out, 'src, T: Deserialize<'src>> JsonArrayView<'out, 'src, T> {
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.positions.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.positions.is_empty()
+    }
+
+    /// the item at `index` without deserializing it, `None` if out of bounds
+    pub fn get_output(&self, index: usize) -> Option<JsonOutput<'out, 'src>> {
+        let start = *self.positions.get(index)?;
+        let end = self.positions.get(index + 1).copied().unwrap_or(self.ast.len());
+        Some(JsonOutput::new(self.parser, &self.ast[start..end]))
+    }
+
+    /// deserialize the item at `index`, `None` if out of bounds
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<Result<T, JsonError>> {
+        self.get_output(index).map(|out| out.parse_into::<T>().map_err(|err| err.at_index(index)))
+    }
+
+    /// deserialize items one by one, in order
+    pub fn iter(&self) -> impl Iterator<Item = Result<T, JsonError>> + use<'_, 'out, 'src, T> {
+        (0..self.len()).filter_map(|index| self.get(index))
+    }
+
+    /// deserialize every item into a `Vec`, stop at the first error
+    #[inline]
+    pub fn to_vec(&self) -> Result<Vec<T>, JsonError> {
+        self.iter().collect()
+    }
+}
+
+impl <'out, 'src> JsonOutput<'out, 'src> {
+    /// view this array without deserializing its items
+    pub fn as_array<T: Deserialize<'src>>(&self) -> Result<JsonArrayView<'_, 'src, T>, JsonError> {
+        let ast = self.ast.as_slice();
+        match ast.first().map(|it| &it.value) {
+            Some(JsonValue::Array(positions, _)) => Ok(JsonArrayView { parser: self.parser, ast, positions, _phantom: PhantomData }),
+            Some(other_type) => Err(JsonError::type_mismatch("array", other_type.get_type_name(), other_type.get_span())),
+            None => Err(JsonError::unexpected_eof("value", Span::default())),
         }
     }
 }
 
-pub trait JsonPsr {
+pub trait JsonPsr<'src> {
     type Out<'out,T> where Self: 'out;
-    fn parse_into<T: Deserialize>(&self) -> Self::Out<'_,T>;
+    fn parse_into<T: Deserialize<'src>>(&self) -> Self::Out<'_,T>;
 }
 
-impl <'out> JsonPsr for JsonOutput<'out> {
+impl <'out, 'src> JsonPsr<'src> for JsonOutput<'out, 'src> {
     type Out<'o, T> = Result<T, JsonError> where Self: 'o;
 
-    fn parse_into<T: Deserialize>(&self) -> Self::Out<'_, T> {
+    fn parse_into<T: Deserialize<'src>>(&self) -> Self::Out<'_, T> {
         T::parse(self)
     }
 }
@@ -236,8 +542,8 @@ mod tests {
         }
     }
 
-    impl Deserialize for Nested {
-        fn parse(out: &JsonOutput<'_>) -> Result<Self, JsonError> {
+    impl Deserialize<'_> for Nested {
+        fn parse(out: &JsonOutput<'_, '_>) -> Result<Self, JsonError> {
             Ok(Self {
                 nested: out.index("nested").ok_or_else(|| JsonError::empty_json(Span::default()))?.parse_into::<u8>()?,
             })
@@ -254,8 +560,8 @@ mod tests {
         hue: Vec<Nested>,
     }
 
-    impl Deserialize for Color {
-        fn parse(out: &JsonOutput<'_>) -> Result<Self, JsonError> {
+    impl Deserialize<'_> for Color {
+        fn parse(out: &JsonOutput<'_, '_>) -> Result<Self, JsonError> {
             Ok(Self {
                 red: out.index("red").ok_or_else(|| JsonError::empty_json(Span::default()))?.parse_into::<u8>()?,
                 green: out.index("green").ok_or_else(|| JsonError::empty_json(Span::default()))?.parse_into::<u8>()?,
@@ -301,4 +607,85 @@ mod tests {
 
         Ok(())
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn parse_null() -> crate::Result<()> {
+        let mut obj = JsonParser::new(r"{ a: null, b: 1 }");
+        let out = obj.parse()?;
+
+        assert_eq!(Ok(None), out.index("a").unwrap().parse_into::<Option<u8>>());
+        assert_eq!(Ok(Some(1)), out.index("b").unwrap().parse_into::<Option<u8>>());
+        assert_eq!(Ok(None), JsonParser::new("null").parse()?.parse_into::<Option<String>>());
+
+        let err = out.index("a").unwrap().parse_into::<u8>().unwrap_err();
+        assert_eq!(&ErrorKind::TypeMismatch { expected: "u8".into(), found: "null".into() }, err.kind());
+
+        Ok(())
+    }
+
+    #[test]
+    fn structured_error_kind() -> crate::Result<()> {
+        let mut obj = JsonParser::new(r"{ big: 256, neg: -1, str: 'a' }");
+        let out = obj.parse()?;
+
+        let overflow = out.index("big").unwrap().parse_into::<u8>().unwrap_err();
+        let negative = out.index("neg").unwrap().parse_into::<u8>().unwrap_err();
+        let mismatch = out.index("str").unwrap().parse_into::<bool>().unwrap_err();
+
+        assert_eq!(&ErrorKind::IntegerOverflow("u8"), overflow.kind());
+        assert_eq!(&ErrorKind::TypeMismatch { expected: "u8".into(), found: "negative integer".into() }, negative.kind());
+        assert_eq!(&ErrorKind::TypeMismatch { expected: "bool".into(), found: "string".into() }, mismatch.kind());
+        assert_eq!(Span::new(7, 10), overflow.span().clone());
+
+        Ok(())
+    }
+
+    #[test]
+    fn borrow_str_from_source() -> crate::Result<()> {
+        let source = r#"{ plain: 'hello', empty: "", escaped: 'a\tb', num: 1 }"#;
+        let mut obj = JsonParser::new(source);
+        let out = obj.parse()?;
+
+        let plain = out.index("plain").unwrap().parse_into::<&str>()?;
+        assert_eq!("hello", plain);
+        // points into the source instead of a copy
+        assert!(source.as_bytes().as_ptr_range().contains(&plain.as_ptr()));
+        assert_eq!(Ok(""), out.index("empty").unwrap().parse_into::<&str>());
+
+        let err = out.index("escaped").unwrap().parse_into::<&str>().unwrap_err();
+        assert_eq!(&ErrorKind::TypeMismatch { expected: "&str".into(), found: "string with escape sequence".into() }, err.kind());
+        let err = out.index("num").unwrap().parse_into::<&str>().unwrap_err();
+        assert_eq!(&ErrorKind::TypeMismatch { expected: "&str".into(), found: "integer".into() }, err.kind());
+
+        assert!(matches!(out.index("plain").unwrap().parse_into::<Cow<str>>()?, Cow::Borrowed("hello")));
+        assert!(matches!(out.index("escaped").unwrap().parse_into::<Cow<str>>()?, Cow::Owned(owned) if owned == "a\tb"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn view_array_lazily() -> crate::Result<()> {
+        let mut obj = JsonParser::new("{ items: [{ nested: 1 }, [2, 3], 'x', { nested: 4 }], num: 1 }");
+        let out = obj.parse()?;
+        let items = out.index("items").unwrap();
+
+        let nested = items.as_array::<Nested>()?;
+        assert_eq!(4, nested.len());
+        assert_eq!(Some(Ok(Nested::new(1))), nested.get(0));
+        assert_eq!(Some(Ok(Nested::new(4))), nested.get(3));
+        assert_eq!(Ok("[2, 3]"), nested.get_output(1).unwrap().to_slice());
+        assert!(nested.to_vec().is_err());
+
+        let err = nested.get(2).unwrap().unwrap_err();
+        assert_eq!("$[2]", err.path().to_string());
+
+        let strs = items.as_array::<&str>()?;
+        assert_eq!(vec![Ok("x")], strs.iter().filter(Result::is_ok).collect::<Vec<_>>());
+
+        let err = out.index("num").unwrap().as_array::<u8>().err().unwrap();
+        assert_eq!(&ErrorKind::TypeMismatch { expected: "array".into(), found: "integer".into() }, err.kind());
+        assert!(JsonParser::new("[]").parse()?.as_array::<u8>()?.is_empty());
+
+        Ok(())
+    }
+}
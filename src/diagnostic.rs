@@ -0,0 +1,220 @@
+use std::fmt::{Display, Write};
+
+use crate::error::JsonError;
+
+/// Visualize a [`JsonError`] on its input source, similar to what `rustc` does.
+///
+/// ```
+/// use jsode::prelude::*;
+///
+/// let src = "{\n  a: 'oops\n}";
+/// let err = JsonParser::new(src).parse().unwrap_err();
+/// let report = Diagnostic::new(&err, src).with_file_name("config.json5").render();
+///
+/// assert!(report.contains("--> config.json5:2:6"));
+/// ```
+#[derive(Debug)]
+pub struct Diagnostic<'a> {
+    error: &'a JsonError,
+    source: &'a str,
+    file_name: Option<String>,
+    hint: Option<String>,
+}
+
+impl<'a> Diagnostic<'a> {
+    pub fn new(error: &'a JsonError, source: &'a str) -> Self {
+        Self { error, source, file_name: None, hint: None }
+    }
+
+    pub fn with_file_name(mut self, file_name: impl Into<String>) -> Self {
+        self.file_name = Some(file_name.into());
+        self
+    }
+
+    pub fn with_hint(mut self, hint: impl Into<String>) -> Self {
+        self.hint = Some(hint.into());
+        self
+    }
+
+    /// render as plain text
+    pub fn render(&self) -> String {
+        self.render_with(&Palette::PLAIN)
+    }
+
+    /// render with ANSI escape codes, suitable for terminal output
+    pub fn render_ansi(&self) -> String {
+        self.render_with(&Palette::ANSI)
+    }
+
+    fn render_with(&self, palette: &Palette) -> String {
+        let Palette { error, accent, bold, reset } = palette;
+        let mut out = String::new();
+        let file_name = self.file_name.as_deref().unwrap_or("<source>");
+
+        let _ = writeln!(out, "{error}error{reset}{bold}: {}{reset}", self.error);
+
+        match self.locate() {
+            Some(Snippet { line_no, col, line, underline_at, underline_len }) => {
+                let gutter = " ".repeat(line_no.to_string().len());
+                // keep tabs so the underline stay aligned with the offending line
+                let padding = line.chars().take(underline_at).map(|c| if c == '\t' { '\t' } else { ' ' }).collect::<String>();
+                let underline = if underline_len > 1 { "^".repeat(underline_len) } else { "^".to_string() };
+
+                let _ = writeln!(out, "{gutter}{accent}-->{reset} {file_name}:{line_no}:{col}");
+                let _ = writeln!(out, "{gutter} {accent}|{reset}");
+                let _ = writeln!(out, "{accent}{line_no} |{reset} {line}");
+                let _ = write!(out, "{gutter} {accent}|{reset} {padding}{error}{underline}{reset}");
+                if !self.error.path().is_root() || self.hint.is_some() {
+                    let _ = write!(out, "\n{gutter} {accent}|{reset}");
+                }
+                self.render_notes(&mut out, &gutter, palette);
+            },
+            None => {
+                let _ = write!(out, " {accent}-->{reset} {file_name}");
+                self.render_notes(&mut out, " ", palette);
+            },
+        }
+
+        out
+    }
+
+    // `= at: $.path` and `= hint: ..` below the snippet
+    fn render_notes(&self, out: &mut String, gutter: &str, Palette { accent, bold, reset, .. }: &Palette) {
+        if !self.error.path().is_root() {
+            let _ = write!(out, "\n{gutter} {accent}={reset} {bold}at{reset}: {}", self.error.path());
+        }
+        if let Some(hint) = &self.hint {
+            let _ = write!(out, "\n{gutter} {accent}={reset} {bold}hint{reset}: {hint}");
+        }
+    }
+
+    // compute the location from the source itself, some errors (mostly from deserialization) carry a
+    // span without line & column
+    fn locate(&self) -> Option<Snippet<'a>> {
+        let span = self.error.span();
+        if span.start == 0 && span.end == 0 && !span.has_location() {
+            return None;
+        }
+
+        let start = floor_char_boundary(self.source, span.start.min(self.source.len()));
+        let end = floor_char_boundary(self.source, span.end.clamp(start, self.source.len()));
+
+        let line_start = self.source[..start].rfind(['\n', '\r']).map_or(0, |pos| pos + 1);
+        let line_end = self.source[start..].find(['\n', '\r']).map_or(self.source.len(), |pos| start + pos);
+        let line_no = self.source[..line_start].matches('\n').count()
+            // lonely carriage return also break line
+            + self.source[..line_start].matches('\r').count()
+            - self.source[..line_start].matches("\r\n").count()
+            + 1;
+
+        let underline_at = self.source[line_start..start].chars().count();
+        // an underline never go through the end of the offending line
+        let underline_len = self.source[start..end.min(line_end)].chars().count();
+
+        Some(Snippet {
+            line_no,
+            col: underline_at + 1,
+            line: &self.source[line_start..line_end],
+            underline_at,
+            underline_len,
+        })
+    }
+}
+
+impl Display for Diagnostic<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.render())
+    }
+}
+
+impl JsonError {
+    /// shortcut for [`Diagnostic::new`]
+    pub fn diagnostic<'a>(&'a self, source: &'a str) -> Diagnostic<'a> {
+        Diagnostic::new(self, source)
+    }
+}
+
+struct Snippet<'a> {
+    line_no: usize,
+    col: usize,
+    line: &'a str,
+    underline_at: usize,
+    underline_len: usize,
+}
+
+struct Palette {
+    error: &'static str,
+    accent: &'static str,
+    bold: &'static str,
+    reset: &'static str,
+}
+
+impl Palette {
+    const PLAIN: Self = Self { error: "", accent: "", bold: "", reset: "" };
+    const ANSI: Self = Self { error: "\x1b[1;31m", accent: "\x1b[1;34m", bold: "\x1b[1m", reset: "\x1b[0m" };
+}
+
+#[inline]
+fn floor_char_boundary(src: &str, mut pos: usize) -> usize {
+    while !src.is_char_boundary(pos) {
+        pos -= 1;
+    }
+    pos
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{core::Span, error::JsonError, parser::JsonParser};
+
+    use super::*;
+
+    #[test]
+    fn render_plain() {
+        let src = "{\n  a: 1,\n  b: 'oops\n}";
+        let err = JsonParser::new(src).parse().unwrap_err();
+        let report = Diagnostic::new(&err, src)
+            .with_file_name("config.json5")
+            .with_hint("close the string with '")
+            .render();
+
+        assert_eq!(format!("error: {err}
+ --> config.json5:3:6
+  |
+3 |   b: 'oops
+  |      ^^^^^
+  |
+  = hint: close the string with '"), report);
+    }
+
+    #[test]
+    fn render_ansi() {
+        let src = "[1, 2 3]";
+        let err = JsonParser::new(src).parse().unwrap_err();
+        let report = err.diagnostic(src).render_ansi();
+
+        assert!(report.starts_with("\x1b[1;31merror\x1b[0m"));
+        assert!(report.contains("<source>:1:7"));
+        assert!(report.contains("\x1b[1;31m^\x1b[0m"));
+    }
+
+    #[test]
+    fn render_without_location() {
+        let err = JsonError::custom("key not found", Span::default());
+
+        assert_eq!("error: key not found\n --> <source>", Diagnostic::new(&err, "{}").render());
+    }
+
+    #[test]
+    fn render_path() {
+        let src = "{ hue: [{ h: 1 }, { h: 'x' }] }";
+        let err = JsonError::type_mismatch("u8", "string", Span::new(24, 25)).at_key("h").at_index(1).at_key("hue");
+
+        assert_eq!("error: cannot convert type string to type u8
+ --> <source>:1:25
+  |
+1 | { hue: [{ h: 1 }, { h: 'x' }] }
+  |                         ^
+  |
+  = at: $.hue[1].h", Diagnostic::new(&err, src).render());
+    }
+}
@@ -4,69 +4,312 @@ use std::fmt::Debug;
 use crate::core::Span;
 use crate::constant::msg;
 
-#[derive(PartialEq, Debug)]
-pub enum ErrorMsg {
-    MissingSingleColon,
-    MissingDoubleColon,
+/// What went wrong, either while parsing or deserializing.
+#[derive(PartialEq, Eq, PartialOrd, Clone, Debug)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// found a token which cannot appear at this position
+    UnexpectedToken { expected: &'static str, found: String },
+    /// the input ended while more tokens are expected
+    UnexpectedEof { expected: &'static str },
+    /// a string missing its closing quote (the `char`)
+    UnterminatedString(char),
+    /// a `/` which is not followed by `/` or `*`
+    InvalidComment,
+    /// escape sequence which cannot be decoded
+    InvalidEscape(String),
+    /// `\uXXXX` escape of a UTF-16 surrogate which is not part of a valid pair
+    UnpairedSurrogate(String),
+    InvalidNumber(String),
+    InvalidUtf8(String),
+    /// a character which cannot start any token
+    UnsupportedChar(char),
+    /// anything after the root value (except whitespaces and comments)
+    TrailingData,
+    /// a JSON5-only construct found while parsing in [`Dialect::Strict`](crate::option::Dialect::Strict)
+    NotAllowed(&'static str),
     EmptyJson,
-    InvalidArray,
+    MissingKey(String),
+    /// the JSON value cannot be converted into the requested type
+    TypeMismatch { expected: String, found: String },
+    /// an object's key cannot be converted into the key type of a map
+    InvalidKey { key: String, reason: String },
+    /// the array has a different number of items than the tuple it is converted into
+    InvalidLength { expected: usize, found: usize },
+    /// the string cannot be converted into `ty`, such as an IP address or a `char`
+    InvalidValue { ty: &'static str, reason: String },
+    /// the tag names none of the variants of an enum, `expected` lists the accepted names
+    UnknownVariant { found: String, expected: &'static [&'static str] },
+    /// the object has a key which the struct does not declare, `expected` lists the accepted keys
+    UnknownKey { found: String, expected: &'static [&'static str] },
+    /// the value matches none of the variants of the untagged enum
+    UnmatchedVariant(&'static str),
+    /// the number is out of range of the requested integer type
+    IntegerOverflow(&'static str),
+    /// an object repeats `key` under [`DuplicateKey::Error`](crate::option::DuplicateKey::Error),
+    /// `first` is the span of its first occurrence while the error's span is the repeated one
+    DuplicateKey { key: String, first: Span },
+    /// `segment` of the path given to [`JsonOutput::pointer`](crate::core::JsonOutput::pointer)
+    /// or [`JsonOutput::get_path`](crate::core::JsonOutput::get_path) names no value
+    PathNotFound { path: String, segment: String },
+    /// the path given to [`JsonOutput::pointer`](crate::core::JsonOutput::pointer)
+    /// or [`JsonOutput::get_path`](crate::core::JsonOutput::get_path) is not well formed
+    InvalidPath { path: String, reason: &'static str },
     Custom(String),
 }
 
+/// One step from a parent value to its child.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub enum PathSegment {
+    Key(String),
+    Index(usize),
+}
+
+/// Location of a value inside the JSON document, displayed as `$.hue[1].h`.
+#[derive(PartialEq, Eq, Clone, Debug, Default)]
+pub struct JsonPath(Vec<PathSegment>);
+
+// path of an error which did not bubble up through any value
+static ROOT_PATH: JsonPath = JsonPath(Vec::new());
+
+impl JsonPath {
+    #[inline]
+    pub fn segments(&self) -> &[PathSegment] {
+        &self.0
+    }
+
+    #[inline]
+    pub fn is_root(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl Display for JsonPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("$")?;
+        for segment in &self.0 {
+            match segment {
+                PathSegment::Key(key) if is_plain_key(key) => write!(f, ".{key}")?,
+                PathSegment::Key(key) => write!(f, "[{key:?}]")?,
+                PathSegment::Index(index) => write!(f, "[{index}]")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+// keys which can be written after a dot without being ambiguous
+fn is_plain_key(key: &str) -> bool {
+    key.chars().next().is_some_and(|c| c.is_alphabetic() || c == '_' || c == '$')
+        && key.chars().all(|c| c.is_alphanumeric() || c == '_' || c == '$')
+}
+
 #[derive(PartialEq)]
 pub struct JsonError {
     pub(crate) span: Span,
-    pub(crate) msg: ErrorMsg,
+    pub(crate) kind: ErrorKind,
+    // boxed to keep `JsonError` small, `None` until the error bubbles up from a nested value
+    pub(crate) path: Option<Box<JsonPath>>,
+    // replace the text of `kind` when displayed, see `#[jsode(msg = "...")]`
+    pub(crate) message: Option<Box<str>>,
 }
 
 #[rustfmt::skip]
-fn display_error(err: &ErrorMsg, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-    match err {
-        ErrorMsg::MissingDoubleColon => write!(f, "{}", msg::MISSING_DOUBLE_COLON),
-        ErrorMsg::MissingSingleColon => write!(f, "{}", msg::MISSING_SINGLE_COLON),
-        ErrorMsg::EmptyJson          => write!(f, "{}", msg::EMPTY_JSON),
-        ErrorMsg::InvalidArray       => write!(f, "{}", msg::INVALID_ARRAY),
-        ErrorMsg::Custom(msg)        => write!(f, "{:?}", msg),
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnexpectedToken { expected, found } => write!(f, "expect {expected}, found {found}"),
+            Self::UnexpectedEof { expected }          => write!(f, "{}, expect {expected}", msg::UNEXPECTED_EOF),
+            Self::UnterminatedString('\'')            => write!(f, "{}", msg::MISSING_SINGLE_COLON),
+            Self::UnterminatedString(_)               => write!(f, "{}", msg::MISSING_DOUBLE_COLON),
+            Self::InvalidComment                      => write!(f, "{}", msg::INVALID_COMMENT),
+            Self::InvalidEscape(escape)               => write!(f, "{}: {escape}", msg::INVALID_ESCAPE),
+            Self::UnpairedSurrogate(escape)           => write!(f, "{}: {escape}", msg::UNPAIRED_SURROGATE),
+            Self::InvalidNumber(reason)               => write!(f, "{}, {reason}", msg::INVALID_NUMBER),
+            Self::InvalidUtf8(reason)                 => write!(f, "{}, {reason}", msg::INVALID_UTF8),
+            Self::UnsupportedChar(c)                  => write!(f, "{} {c:?}", msg::NOT_SUPPORT_TOKEN),
+            Self::TrailingData                        => write!(f, "{}", msg::TRAILING_DATA),
+            Self::NotAllowed(construct)               => write!(f, "{construct} is not allowed in strict mode"),
+            Self::EmptyJson                           => write!(f, "{}", msg::EMPTY_JSON),
+            Self::MissingKey(key)                     => write!(f, "key not found: `{key}`"),
+            Self::TypeMismatch { expected, found }    => write!(f, "cannot convert type {found} to type {expected}"),
+            Self::InvalidKey { key, reason }          => write!(f, "invalid key `{key}`, {reason}"),
+            Self::InvalidLength { expected, found }   => write!(f, "invalid length {found}, expect an array of {expected} items"),
+            Self::InvalidValue { ty, reason }         => write!(f, "invalid value for type {ty}, {reason}"),
+            Self::UnknownVariant { found, expected }  => write!(f, "unknown variant `{found}`, expect one of {}", expected.iter().map(|it| format!("`{it}`")).collect::<Vec<_>>().join(", ")),
+            Self::UnknownKey { found, expected }      => write!(f, "unknown key `{found}`, expect one of {}", expected.iter().map(|it| format!("`{it}`")).collect::<Vec<_>>().join(", ")),
+            Self::UnmatchedVariant(ty)                => write!(f, "data did not match any variant of enum {ty}"),
+            Self::IntegerOverflow(ty)                 => write!(f, "number out of range of type {ty}"),
+            Self::DuplicateKey { key, first } if first.has_location()
+                                                      => write!(f, "{}: `{key}`, first defined at line {}, column {}", msg::DUPLICATE_KEY, first.row, first.col),
+            Self::DuplicateKey { key, first }         => write!(f, "{}: `{key}`, first defined at {}..{}", msg::DUPLICATE_KEY, first.start, first.end),
+            Self::PathNotFound { path, segment }      => write!(f, "no value at segment `{segment}` of path `{path}`"),
+            Self::InvalidPath { path, reason }        => write!(f, "invalid path `{path}`, {reason}"),
+            Self::Custom(msg)                         => write!(f, "{msg}"),
+        }
     }
 }
 
 impl Debug for JsonError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        writeln!(f, "source file panic at {}..{}", self.span.start, self.span.end)?;
-        display_error(&self.msg, f)
+        if self.span.has_location() {
+            writeln!(f, "source file panic at line {}, column {} ({}..{})", self.span.row, self.span.col, self.span.start, self.span.end)?;
+        } else {
+            writeln!(f, "source file panic at {}..{}", self.span.start, self.span.end)?;
+        }
+        if !self.path().is_root() {
+            writeln!(f, "at path {}", self.path())?;
+        }
+        Display::fmt(self, f)
     }
 }
 
 #[rustfmt::skip]
 impl Display for JsonError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        display_error(&self.msg, f)
+        match &self.message {
+            Some(message) => f.write_str(message),
+            None          => Display::fmt(&self.kind, f),
+        }
     }
 }
 
+impl std::error::Error for JsonError {}
+
 #[rustfmt::skip]
 impl JsonError {
+    #[inline]
+    pub const fn new(kind: ErrorKind, span: Span) -> Self {
+        Self { span, kind, path: None, message: None, }
+    }
+
+    #[inline]
+    pub const fn kind(&self) -> &ErrorKind {
+        &self.kind
+    }
+
+    #[inline]
+    pub const fn span(&self) -> &Span {
+        &self.span
+    }
+
+    /// where the error happened, `$` if the error is not related to any nested value
+    #[inline]
+    pub const fn path(&self) -> &JsonPath {
+        match &self.path {
+            Some(path) => path,
+            None => &ROOT_PATH,
+        }
+    }
+
+    /// the custom message displayed instead of the kind's text
+    #[inline]
+    pub fn message(&self) -> Option<&str> {
+        self.message.as_deref()
+    }
+
+    /// display `message` instead of the kind's text, the kind, span and path are kept
+    pub fn with_message(mut self, message: impl Into<String>) -> Self {
+        self.message = Some(message.into().into_boxed_str());
+        self
+    }
+
+    /// prepend object's key into the path, used when the error bubbles up from a property
+    pub fn at_key(mut self, key: impl Into<String>) -> Self {
+        self.path.get_or_insert_default().0.insert(0, PathSegment::Key(key.into()));
+        self
+    }
+
+    /// prepend array's index into the path, used when the error bubbles up from an item
+    pub fn at_index(mut self, index: usize) -> Self {
+        self.path.get_or_insert_default().0.insert(0, PathSegment::Index(index));
+        self
+    }
+
     pub fn custom(msg: impl Into<String>, span: Span) -> Self {
-        Self { span, msg: ErrorMsg::Custom(msg.into()), }
+        Self::new(ErrorKind::Custom(msg.into()), span)
     }
 
     pub fn missing_single_colon(span: Span) -> Self {
-        Self { span, msg: ErrorMsg::MissingSingleColon, }
+        Self::new(ErrorKind::UnterminatedString('\''), span)
     }
 
     pub fn missing_double_colon(span: Span) -> Self {
-        Self { span, msg: ErrorMsg::MissingDoubleColon, }
+        Self::new(ErrorKind::UnterminatedString('"'), span)
+    }
+
+    pub fn unexpected_token(expected: &'static str, found: impl Into<String>, span: Span) -> Self {
+        Self::new(ErrorKind::UnexpectedToken { expected, found: found.into() }, span)
+    }
+
+    pub fn unexpected_eof(expected: &'static str, span: Span) -> Self {
+        Self::new(ErrorKind::UnexpectedEof { expected }, span)
     }
 
-    pub fn invalid_array(span: Span) -> Self {
-        Self { span, msg: ErrorMsg::InvalidArray, }
+    pub fn missing_key(key: impl Into<String>, span: Span) -> Self {
+        Self::new(ErrorKind::MissingKey(key.into()), span)
     }
 
-    pub fn invalid_type(span: Span, ty: impl Into<String>) -> Self {
-        Self { span, msg: ErrorMsg::Custom(format!("Invalid type, expect {}", ty.into())), }
+    pub fn type_mismatch(expected: impl Into<String>, found: impl Into<String>, span: Span) -> Self {
+        Self::new(ErrorKind::TypeMismatch { expected: expected.into(), found: found.into() }, span)
+    }
+
+    pub fn unknown_variant(found: impl Into<String>, expected: &'static [&'static str], span: Span) -> Self {
+        Self::new(ErrorKind::UnknownVariant { found: found.into(), expected }, span)
+    }
+
+    pub fn unknown_key(found: impl Into<String>, expected: &'static [&'static str], span: Span) -> Self {
+        Self::new(ErrorKind::UnknownKey { found: found.into(), expected }, span)
+    }
+
+    pub fn invalid_number(reason: impl Into<String>, span: Span) -> Self {
+        Self::new(ErrorKind::InvalidNumber(reason.into()), span)
+    }
+
+    pub fn path_not_found(path: impl Into<String>, segment: impl Into<String>, span: Span) -> Self {
+        Self::new(ErrorKind::PathNotFound { path: path.into(), segment: segment.into() }, span)
+    }
+
+    pub fn invalid_path(path: impl Into<String>, reason: &'static str, span: Span) -> Self {
+        Self::new(ErrorKind::InvalidPath { path: path.into(), reason }, span)
     }
 
     pub fn empty_json(span: Span) -> Self {
-        Self { span, msg: ErrorMsg::EmptyJson, }
+        Self::new(ErrorKind::EmptyJson, span)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_path() {
+        let err = JsonError::missing_key("h", Span::default())
+            .at_index(1)
+            .at_key("hue")
+            .at_key("first name");
+
+        assert_eq!("$[\"first name\"].hue[1]", err.path().to_string());
+        assert_eq!(&ErrorKind::MissingKey("h".into()), err.kind());
+        assert_eq!("key not found: `h`", err.to_string());
+    }
+
+    #[test]
+    fn custom_message() {
+        let err = JsonError::missing_key("h", Span::new(1, 2)).with_message("hue is required").at_key("hue");
+
+        assert_eq!("hue is required", err.to_string());
+        assert_eq!(Some("hue is required"), err.message());
+        assert_eq!(&ErrorKind::MissingKey("h".into()), err.kind());
+        assert_eq!(&Span::new(1, 2), err.span());
+        assert_eq!("$.hue", err.path().to_string());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn small_error() {
+        // below the threshold of clippy's `result_large_err`
+        assert!(std::mem::size_of::<JsonError>() < 128);
+        assert!(JsonError::empty_json(Span::default()).path().is_root());
+    }
+}
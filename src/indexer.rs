@@ -1,4 +1,11 @@
-use crate::{common, core::{JsonBlock, JsonOutput, JsonValue}};
+use std::borrow::Cow;
+
+use crate::{
+    constant::msg,
+    core::{JsonBlock, JsonOutput, JsonValue, Result},
+    error::{JsonError, PathSegment},
+    parser::JsonParser,
+};
 
 pub enum Key<'k> {
     Str(&'k str),
@@ -7,13 +14,13 @@ pub enum Key<'k> {
 
 impl<'k> From<&'k str> for Key<'k> {
     fn from(value: &'k str) -> Self {
-        return Key::Str(value);
+        Key::Str(value)
     }
 }
 
 impl<'k> From<usize> for Key<'k> {
     fn from(value: usize) -> Self {
-        return Key::Int(value);
+        Key::Int(value)
     }
 }
 
@@ -23,34 +30,184 @@ pub trait JsonIdx {
 }
 
 
-impl <'out> JsonIdx for JsonOutput<'out> {
-    type Out<'o> = Option<JsonOutput<'o>> where Self: 'o;
+impl <'out, 'src> JsonIdx for JsonOutput<'out, 'src> {
+    type Out<'o> = Option<JsonOutput<'o, 'src>> where Self: 'o;
 
     fn index<'a>(&self, key: impl Into<Key<'a>>) -> Self::Out<'_> {
-        let block = self.ast.as_slice().first();
-        match (key.into(), block) {
-            // the `pos` is relative position of value with parent object
-            // now the value is the first block of AST
-            (Key::Str(key_str), Some(JsonBlock { value: JsonValue::Object(obj, _), .. })) => obj
-                .get(&(common::hash_str(key_str) as usize))
-                .map(|pos| JsonOutput::new(self.parser, &self.ast.as_slice()[*pos..])),
-            (Key::Int(key_int), Some(JsonBlock { value: JsonValue::Array(positions, _), .. })) if key_int < positions.len() => {
-                let ast_slice = self.ast.as_slice();
-                let ast_len = ast_slice.len();
-                let start = positions[key_int];
-                let end = positions.get(key_int + 1);
-                let range = end.map(|e| start..*e).unwrap_or(start..ast_len);
-                Some(JsonOutput::new(self.parser, &ast_slice[range]))
+        child(self.parser, self.ast.as_slice(), key.into()).map(|ast| JsonOutput::new(self.parser, ast))
+    }
+}
+
+// AST of the value found under `key`, which is now the first block
+fn child<'a>(parser: &JsonParser<'_>, ast: &'a [JsonBlock], key: Key<'_>) -> Option<&'a [JsonBlock]> {
+    match (key, ast.first()) {
+        // the `pos` is relative position of value with parent object
+        (Key::Str(key_str), Some(JsonBlock { value: JsonValue::Object(obj, _), .. })) => obj
+            .get(parser, key_str)
+            .map(|pos| &ast[pos..]),
+        (Key::Int(key_int), Some(JsonBlock { value: JsonValue::Array(positions, _), .. })) if key_int < positions.len() => {
+            let start = positions[key_int];
+            let end = positions.get(key_int + 1).copied().unwrap_or(ast.len());
+            Some(&ast[start..end])
+        },
+        _ => None,
+    }
+}
+
+impl <'out, 'src> JsonOutput<'out, 'src> {
+    /// Value found through a [JSON Pointer](https://www.rfc-editor.org/rfc/rfc6901) such as `/servers/0/host`,
+    /// `~1` stands for `/` and `~0` for `~` inside a key, the empty pointer is the value itself.
+    ///
+    /// ```
+    /// use jsode::prelude::*;
+    ///
+    /// let mut src = JsonParser::new("{ servers: [{ host: 'localhost' }], 'a/b': 1 }");
+    /// let ast = src.parse()?;
+    ///
+    /// assert_eq!(Ok("localhost"), ast.pointer("/servers/0/host")?.to_slice());
+    /// assert_eq!(Ok("1"), ast.pointer("/a~1b")?.to_slice());
+    /// assert_eq!("no value at segment `1` of path `/servers/1/host`", ast.pointer("/servers/1/host").unwrap_err().to_string());
+    /// # Ok::<(), JsonError>(())
+    /// ```
+    pub fn pointer(&self, pointer: &str) -> Result<JsonOutput<'_, 'src>> {
+        let segments = pointer_segments(pointer).map_err(|reason| JsonError::invalid_path(pointer, reason, self.get_span()))?;
+        self.walk(pointer, segments)
+    }
+
+    /// Value found through a dotted path such as `servers[0].host`, also written `servers.0.host` or `$.servers[0].host`.
+    ///
+    /// A key having `.` or `[` is quoted inside brackets, `['a.b']` or `["a.b"]`, where `\` escapes the next character.
+    ///
+    /// ```
+    /// use jsode::prelude::*;
+    ///
+    /// let mut src = JsonParser::new("{ servers: [{ host: 'localhost' }], 'a.b': 1 }");
+    /// let ast = src.parse()?;
+    ///
+    /// assert_eq!(Ok("localhost"), ast.get_path("servers[0].host")?.to_slice());
+    /// assert_eq!(Ok("1"), ast.get_path("['a.b']")?.to_slice());
+    /// assert_eq!("$.servers[0]", ast.get_path("servers[0].port").unwrap_err().path().to_string());
+    /// # Ok::<(), JsonError>(())
+    /// ```
+    pub fn get_path(&self, path: &str) -> Result<JsonOutput<'_, 'src>> {
+        let segments = dotted_segments(path).map_err(|reason| JsonError::invalid_path(path, reason, self.get_span()))?;
+        self.walk(path, segments)
+    }
+
+    // a segment names the key of an object, or the index of an array when made of digits,
+    // the error points at the last value reached and its path leads to it
+    fn walk(&self, path: &str, segments: Vec<Cow<'_, str>>) -> Result<JsonOutput<'_, 'src>> {
+        let mut ast = self.ast.as_slice();
+        let mut reached = Vec::with_capacity(segments.len());
+        for segment in segments {
+            let found = match ast.first().map(|it| &it.value) {
+                Some(JsonValue::Object(..)) => child(self.parser, ast, Key::Str(&segment))
+                    .map(|it| (it, PathSegment::Key(segment.to_string()))),
+                Some(JsonValue::Array(..)) => parse_index(&segment)
+                    .and_then(|index| child(self.parser, ast, Key::Int(index)).map(|it| (it, PathSegment::Index(index)))),
+                _ => None,
+            };
+            let Some((next, step)) = found else {
+                let span = ast.first().map(|it| it.value.get_span()).unwrap_or_default();
+                let err = JsonError::path_not_found(path, segment, span);
+                return Err(reached.into_iter().rev().fold(err, |err, step| match step {
+                    PathSegment::Key(key) => err.at_key(key),
+                    PathSegment::Index(index) => err.at_index(index),
+                }));
+            };
+            ast = next;
+            reached.push(step);
+        }
+        Ok(JsonOutput::new(self.parser, ast))
+    }
+}
+
+// `0` or digits without leading zero, as RFC 6901 requires for an array index
+fn parse_index(segment: &str) -> Option<usize> {
+    let canonical = segment == "0" || !segment.starts_with('0') && !segment.is_empty() && segment.bytes().all(|it| it.is_ascii_digit());
+    canonical.then(|| segment.parse().ok()).flatten()
+}
+
+fn pointer_segments(pointer: &str) -> std::result::Result<Vec<Cow<'_, str>>, &'static str> {
+    if pointer.is_empty() {
+        return Ok(Vec::new());
+    }
+    let tokens = pointer.strip_prefix('/').ok_or(msg::POINTER_START)?;
+    tokens.split('/').map(|token| {
+        if !token.contains('~') {
+            return Ok(Cow::Borrowed(token));
+        }
+        let mut parts = token.split('~');
+        let mut decoded = String::from(parts.next().unwrap_or_default());
+        for part in parts {
+            match part.as_bytes().first() {
+                Some(b'0') => decoded.push('~'),
+                Some(b'1') => decoded.push('/'),
+                _ => return Err(msg::POINTER_ESCAPE),
+            }
+            decoded.push_str(&part[1..]);
+        }
+        Ok(Cow::Owned(decoded))
+    }).collect()
+}
+
+fn dotted_segments(path: &str) -> std::result::Result<Vec<Cow<'_, str>>, &'static str> {
+    // `$` is the root of `JsonPath`, not the start of a key such as `$price`
+    let mut rest = path.strip_prefix('$').filter(|it| it.is_empty() || it.starts_with(['.', '['])).unwrap_or(path);
+    let mut segments = Vec::new();
+    while !rest.is_empty() {
+        if let Some(bracketed) = rest.strip_prefix('[') {
+            let (segment, after) = bracket_segment(bracketed)?;
+            segments.push(segment);
+            rest = after;
+            continue;
+        }
+        // the first key has no dot in front of it
+        let key = match rest.strip_prefix('.') {
+            Some(key) => key,
+            None if segments.is_empty() => rest,
+            None => return Err(msg::PATH_MISSING_DOT),
+        };
+        let end = key.find(['.', '[']).unwrap_or(key.len());
+        if end == 0 {
+            return Err(msg::PATH_EMPTY_SEGMENT);
+        }
+        segments.push(Cow::Borrowed(&key[..end]));
+        rest = &key[end..];
+    }
+    Ok(segments)
+}
+
+// `0]` or `'key']`, returns the segment and what follows the closing bracket
+fn bracket_segment(bracketed: &str) -> std::result::Result<(Cow<'_, str>, &str), &'static str> {
+    let Some(quote) = bracketed.chars().next().filter(|it| matches!(it, '\'' | '"')) else {
+        let end = bracketed.find(']').ok_or(msg::PATH_UNCLOSED_BRACKET)?;
+        let index = &bracketed[..end];
+        if index.is_empty() || !index.bytes().all(|it| it.is_ascii_digit()) {
+            return Err(msg::PATH_INVALID_BRACKET);
+        }
+        return Ok((Cow::Borrowed(index), &bracketed[end + 1..]));
+    };
+
+    let mut key = String::new();
+    let mut chars = bracketed[1..].char_indices();
+    while let Some((at, c)) = chars.next() {
+        match c {
+            '\\' => key.extend(chars.next().map(|(_, it)| it)),
+            c if c == quote => {
+                let after = bracketed[1 + at + 1..].strip_prefix(']').ok_or(msg::PATH_UNCLOSED_BRACKET)?;
+                return Ok((Cow::Owned(key), after));
             },
-            _ => None,
+            c => key.push(c),
         }
     }
+    Err(msg::PATH_UNCLOSED_BRACKET)
 }
 
 #[rustfmt::skip]
 #[cfg(test)]
 mod tests {
-    use crate::{indexer::JsonIdx, parser::JsonParser};
+    use crate::{constant::msg, indexer::{dotted_segments, pointer_segments, JsonIdx}, parser::JsonParser};
 
     #[test]
     fn index_json_item() {
@@ -106,4 +263,74 @@ mod tests {
 
         assert_eq!(None, ast.index("b"))
     }
+
+    #[test]
+    fn iterate_object_keys() {
+        let mut object = JsonParser::new("{ z: 1, 'a b': [1, { c: 2 }], \"m\": { n: 3 }, x: 4 }");
+        let        ast = object.parse().unwrap();
+
+        assert_eq!(vec!["z", "a b", "m", "x"], ast.keys().collect::<Vec<_>>());
+        assert_eq!(4, ast.len());
+        assert!(ast.contains_key("a b"));
+        assert!(!ast.contains_key("c"));
+
+        let entries = ast.entries()
+            .map(|(key, value)| format!("{key}={}", value.to_slice().unwrap()))
+            .collect::<Vec<_>>();
+        assert_eq!(vec!["z=1", "a b=[1, { c: 2 }]", "m={ n: 3 }", "x=4"], entries);
+        assert_eq!(vec!["n"], ast.index("m").unwrap().keys().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn iterate_keys_of_non_object() {
+        let mut array = JsonParser::new("[1, 2]");
+        let       ast = array.parse().unwrap();
+
+        assert_eq!(0, ast.keys().count());
+        assert_eq!(2, ast.len());
+        assert!(!ast.contains_key("0"));
+
+        let mut empty = JsonParser::new("{}");
+        assert!(empty.parse().unwrap().is_empty());
+    }
+
+    #[test]
+    fn repeated_key_keeps_first_place() {
+        let mut object = JsonParser::new("{ a: 1, b: 2, a: 3 }");
+        let        ast = object.parse().unwrap();
+
+        assert_eq!(vec!["a", "b"], ast.keys().collect::<Vec<_>>());
+        assert_eq!(Ok("3"), ast.index("a").unwrap().to_slice());
+    }
+
+    #[test]
+    fn split_pointer() {
+        assert!(pointer_segments("").unwrap().is_empty());
+        assert_eq!(vec![""], pointer_segments("/").unwrap());
+        assert_eq!(vec!["a", "0", "b"], pointer_segments("/a/0/b").unwrap());
+        assert_eq!(vec!["a/b", "m~n", "~1"], pointer_segments("/a~1b/m~0n/~01").unwrap());
+        assert_eq!(Err(msg::POINTER_START), pointer_segments("a/b"));
+        assert_eq!(Err(msg::POINTER_ESCAPE), pointer_segments("/a~2"));
+        assert_eq!(Err(msg::POINTER_ESCAPE), pointer_segments("/a~"));
+    }
+
+    #[test]
+    fn split_dotted_path() {
+        assert!(dotted_segments("").unwrap().is_empty());
+        assert!(dotted_segments("$").unwrap().is_empty());
+        assert_eq!(vec!["a", "0", "b"], dotted_segments("a[0].b").unwrap());
+        assert_eq!(vec!["a", "0", "b"], dotted_segments("a.0.b").unwrap());
+        assert_eq!(vec!["hue", "1", "h"], dotted_segments("$.hue[1].h").unwrap());
+        assert_eq!(vec!["$price"], dotted_segments("$price").unwrap());
+        assert_eq!(vec!["first name", "a.b", "it's", "\"q\""], dotted_segments(r#"["first name"]['a.b']['it\'s']["\"q\""]"#).unwrap());
+        assert_eq!(vec!["0", "1"], dotted_segments("[0][1]").unwrap());
+        assert_eq!(Err(msg::PATH_EMPTY_SEGMENT), dotted_segments("a..b"));
+        assert_eq!(Err(msg::PATH_EMPTY_SEGMENT), dotted_segments("a."));
+        assert_eq!(Err(msg::PATH_MISSING_DOT), dotted_segments("a[0]b"));
+        assert_eq!(Err(msg::PATH_INVALID_BRACKET), dotted_segments("a[x]"));
+        assert_eq!(Err(msg::PATH_INVALID_BRACKET), dotted_segments("a[]"));
+        assert_eq!(Err(msg::PATH_UNCLOSED_BRACKET), dotted_segments("a[0"));
+        assert_eq!(Err(msg::PATH_UNCLOSED_BRACKET), dotted_segments("a['b"));
+        assert_eq!(Err(msg::PATH_UNCLOSED_BRACKET), dotted_segments("a['b'"));
+    }
 }
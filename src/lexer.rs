@@ -1,13 +1,19 @@
 use std::{marker::PhantomData, ptr};
-use jsode_macro::reflection;
-
-use crate::{constant, core::{Decimal, JsonToken, NumType, Sign, Span, StrType}, error::JsonError};
+use crate::{constant, core::{JsonToken, JsonType, NumType, Sign, Span, StrType}, error::{ErrorKind, JsonError}, option::Dialect};
 
 #[derive(PartialEq, Debug)]
 pub struct Tokenizer<'a> {
     ptr: *const u8,
     pub(crate) pos: usize,
     size: usize,
+    dialect: Dialect,
+    // start position of each line, recorded while `pos` moving forward
+    lines: Vec<usize>,
+    // every line break before this position was already recorded in `lines`
+    scanned: usize,
+    // the latest located position (pos, line, col), tokens are located in order
+    // so counting column from here avoid rescanning the whole line
+    located: (usize, usize, usize),
     _phantom: PhantomData<&'a [u8]>,
 }
 
@@ -17,6 +23,10 @@ impl <'a> From<&'a str> for Tokenizer<'a> {
             ptr: slice.as_ptr(),
             pos: 0,
             size: slice.len(),
+            dialect: Dialect::Json5,
+            lines: vec![0],
+            scanned: 0,
+            located: (0, 1, 1),
             _phantom: PhantomData,
         }
     }
@@ -26,6 +36,40 @@ impl <'a> Iterator for Tokenizer<'a> {
     type Item = JsonToken;
 
     fn next(&mut self) -> Option<Self::Item> {
+        let token = self.lex()?;
+
+        // JSON5 number grammar is a superset of the RFC 8259 one,
+        // narrow it down after the whole number was lexed
+        let mut token = match token {
+            JsonToken::Data(JsonType::Num(NumType::Hex(_)), span) if self.dialect.is_strict() => JsonToken::error(ErrorKind::NotAllowed(constant::msg::STRICT_HEXADECIMAL), span.start, span.end),
+            JsonToken::Data(JsonType::Num(NumType::Integer(_) | NumType::Decimal(_)), ref span) if self.dialect.is_strict() => match check_strict_number(self.take_raw(span.clone())) {
+                Ok(_) => token,
+                Err(kind) => JsonToken::error(kind, span.start, span.end),
+            },
+            other => other,
+        };
+
+        let span = match &mut token {
+            JsonToken::Data(_, span)
+            | JsonToken::Punct(_, span)
+            | JsonToken::Error(_, span)
+            | JsonToken::Comment(span) => span,
+        };
+        let (row, col) = self.locate(span.start);
+        span.row = row;
+        span.col = col;
+
+        Some(token)
+    }
+}
+
+impl <'a> Tokenizer<'a> {
+    pub(crate) const fn with_dialect(mut self, dialect: Dialect) -> Self {
+        self.dialect = dialect;
+        self
+    }
+
+    fn lex(&mut self) -> Option<JsonToken> {
         let at = self.pos;
         let next_item = self.next_item()?;
 
@@ -35,27 +79,18 @@ impl <'a> Iterator for Tokenizer<'a> {
             //     %x09 /              ; Horizontal tab
             //     %x0A /              ; Line feed or New line
             //     %x0D )              ; Carriage return
-            constant::ascii::HORIZONTAL_TAB
-            | constant::ascii::SPACE
-            | constant::ascii::LINE_FEED
-            | constant::ascii::CARRIAGE_RETURN
-            | constant::ascii::FORM_FEED
-            | constant::ascii::NON_BREAKING_SPACE
-            | constant::ascii::BACKSPACE => loop {
-                // parse all character wrapped inside single quote
-                let Some(next_item) = self.next_item() else {
-                    break JsonToken::whitespace(at, self.pos).into()
-                };
-                if !matches!(next_item, constant::ascii::HORIZONTAL_TAB
-                    | constant::ascii::SPACE
-                    | constant::ascii::LINE_FEED
-                    | constant::ascii::CARRIAGE_RETURN
-                    | constant::ascii::FORM_FEED
-                    | constant::ascii::NON_BREAKING_SPACE
-                    | constant::ascii::BACKSPACE) {
-                    self.step_back();
-                    break Some(JsonToken::whitespace(at, self.pos))
+            // JSON5 also accepts VT, FF, NBSP, U+2028, U+2029, BOM and the other space separators (Zs)
+            _ if self.whitespace_len(at, self.dialect) > 0 => {
+                self.pos = at;
+                // consume all continuous whitespaces
+                while let len @ 1.. = self.whitespace_len(self.pos, self.dialect) {
+                    self.pos += len;
                 }
+                JsonToken::whitespace(at, self.pos).into()
+            },
+            _ if self.whitespace_len(at, Dialect::Json5) > 0 => {
+                self.pos = at + self.whitespace_len(at, Dialect::Json5);
+                JsonToken::error(ErrorKind::NotAllowed(constant::msg::STRICT_WHITESPACE), at, self.pos).into()
             },
             b'{' => JsonToken::open_curly(at).into(),
             b'}' => JsonToken::close_curly(at).into(),
@@ -64,9 +99,10 @@ impl <'a> Iterator for Tokenizer<'a> {
             b':' => JsonToken::colon(at).into(),
             b',' => JsonToken::comma(at).into(),
             // comment
+            b'/' if self.dialect.is_strict() => JsonToken::error(ErrorKind::NotAllowed(constant::msg::STRICT_COMMENT), at, self.pos).into(),
             b'/' => {
                 let Some(next_item) = self.next_item() else {
-                    return JsonToken::error("Invalid comment, must follow by another '/' (single-line comment) or '*' (multi-line comment)", at, self.pos).into();
+                    return JsonToken::error(ErrorKind::InvalidComment, at, self.pos).into();
                 };
                 // single-line comment
                 if next_item.eq(&b'/') {
@@ -79,229 +115,301 @@ impl <'a> Iterator for Tokenizer<'a> {
                     return JsonToken::comment(at, self.pos).into();
                 }
 
-                JsonToken::error("Invalid comment, must follow by another '/' (single-line comment) or '*' (multi-line comment)", at, self.pos).into()
+                JsonToken::error(ErrorKind::InvalidComment, at, self.pos).into()
             },
             // string and literal
-            b'\'' => { let mut str_tokens = Vec::<StrType>::new(); loop {
-                // parse all character wrapped inside single quote
-                let Some(next_item) = self.next_item() else {
-                    break JsonToken::error(constant::msg::MISSING_SINGLE_COLON, at, self.pos).into();
-                };
-                if next_item.eq(&constant::ascii::ESCAPE)  {
-                    let Some(next_it) = self.next_item() else {
-                        break JsonToken::error(constant::msg::MISSING_SINGLE_COLON, at, self.pos).into();
-                    };
-
-                    // handle '\xXX'
-                    if next_it.eq(&b'x') {
-                        match self.next_exact_until(2, |item| item.is_ascii_hexdigit()) {
-                            Ok(_) => {
-                                str_tokens.push(StrType::Ascii(Span::new(self.pos - 3, self.pos)));
-                                continue;
-                            },
-                            Err(err) => break JsonToken::from(err).into(),
-                        }
-                    }
+            b'\'' if self.dialect.is_strict() => JsonToken::error(ErrorKind::NotAllowed(constant::msg::STRICT_SINGLE_QUOTE), at, self.pos).into(),
+            b'\'' => self.next_str(at, constant::ascii::SINGLE_QUOTE).into(),
+            b'"' => self.next_str(at, constant::ascii::DOUBLE_QUOTE).into(),
+            // number
+            b'+' if self.dialect.is_strict() => JsonToken::error(ErrorKind::NotAllowed(constant::msg::STRICT_PLUS_SIGN), at, self.pos).into(),
+            b'.' if self.dialect.is_strict() => JsonToken::error(ErrorKind::NotAllowed(constant::msg::STRICT_LEADING_DOT), at, self.pos).into(),
+            b'-' | b'+' | b'.' | b'0'..=b'9' => self.next_number(at).into(),
+            // identifier or keyword
+            b'a'..=b'z' | b'A'..=b'Z' | b'_' | b'$' | b'\\' => self.next_ident(at).into(),
+            0x80.. if self.char_at(at).is_some_and(is_ident_start) => self.next_ident(at).into(),
+            unknown_token => {
+                // the token may be the first byte of a multi-bytes character, cover it as a whole
+                let unknown_char = self.char_at(at).unwrap_or(unknown_token as char);
+                self.pos = (at + unknown_char.len_utf8()).min(self.size);
+                JsonToken::error(ErrorKind::UnsupportedChar(unknown_char), at, self.pos).into()
+            },
+        }
+    }
+}
 
-                    // handle '\uXXXX'
-                    if next_it.eq(&b'u') {
-                        match self.next_exact_until(4, |item| item.is_ascii_hexdigit()) {
-                            Ok(_) => {
-                                str_tokens.push(StrType::Unicode(Span::new(self.pos - 5, self.pos)));
-                                continue;
-                            },
-                            Err(err) => break JsonToken::from(err).into(),
-                        }
-                    }
+impl <'a> Tokenizer<'a> {
+    #[inline]
+    pub const fn take_raw(&self, span: Span) -> &'a [u8] {
+        unsafe { std::slice::from_raw_parts(self.ptr.add(span.start), span.gap()) }
+    }
 
-                    if matches!(next_it, b'\'' | b'\"' | b'\\' | b'b' | b'f' | b'n' | b'r' | b't' | b'v' | b'0') {
-                        str_tokens.push(StrType::Special(Span::new(self.pos - 1, self.pos)));
-                        continue;
-                    }
+    #[inline]
+    pub fn take_slice(&self, span: Span) -> Result<&'a str, JsonError> {
+        unsafe {
+            let slice = std::slice::from_raw_parts(self.ptr.add(span.start), span.gap());
+            std::str::from_utf8(slice)
+                .map_err(|err| JsonError::new(ErrorKind::InvalidUtf8(err.to_string()), span))
+        }
+    }
 
-                    if next_it.is_ascii_digit() {
-                        return JsonToken::error(format!("{}: {}", constant::msg::INVALID_ESCAPE, char::from_u32(next_it as u32).unwrap()), at, self.pos).into();
-                    }
+    /// compute the 1-based line and column (in chars) of position `at`
+    pub(crate) fn locate(&mut self, at: usize) -> (usize, usize) {
+        let at = at.min(self.size);
+        // record all line breaks until `at`, `\r\n` is counted as a single one
+        while self.scanned < at {
+            let item = unsafe { ptr::read(self.ptr.add(self.scanned)) };
+            self.scanned += 1;
+            let is_line_break = item == constant::ascii::LINE_FEED
+                || (item == constant::ascii::CARRIAGE_RETURN && self.peek_at(self.scanned) != Some(constant::ascii::LINE_FEED));
+            if is_line_break {
+                self.lines.push(self.scanned);
+            }
+        }
 
-                    str_tokens.push(StrType::Escape(Span::new(self.pos - 1, self.pos)));
-                }
+        let line = self.lines.partition_point(|start| *start <= at);
+        let (last_pos, last_line, last_col) = self.located;
+        let (from, col) = if last_line == line && last_pos <= at {
+            (last_pos, last_col)
+        } else {
+            (self.lines[line - 1], 1)
+        };
+        // count chars instead of bytes, skip UTF-8 continuation bytes
+        let col = col + self.take_raw(Span::new(from, at)).iter()
+            .filter(|it| (**it & 0xC0) != 0x80)
+            .count();
+        self.located = (at, line, col);
+        (line, col)
+    }
 
-                if next_item.eq(&constant::ascii::SINGLE_QUOTE) {
-                    break JsonToken::str(str_tokens, at, self.pos).into()
-                }
+    #[inline]
+    const fn peek_at(&self, pos: usize) -> Option<u8> {
+        if pos >= self.size {
+            None
+        } else {
+            Some(unsafe { ptr::read(self.ptr.add(pos)) })
+        }
+    }
 
-                let Span { start, end, .. } = self.move_backward_then_consume_until(1, |it| matches!(it, b'\\' | b'\''));
-                str_tokens.push(StrType::Str(Span::new(start, end)));
-            }},
-            b'"' => { let mut str_tokens = Vec::<StrType>::new();  loop {
-                // parse all character wrapped inside double quote
-                let Some(next_item) = self.next_item() else {
-                    break JsonToken::error(constant::msg::MISSING_DOUBLE_COLON, at, self.pos).into();
+    // byte length of the whitespace character at `pos`, `0` if it is not a whitespace of `dialect`
+    #[inline]
+    fn whitespace_len(&self, pos: usize, dialect: Dialect) -> usize {
+        match (dialect, self.peek_at(pos)) {
+            (_, Some(constant::ascii::HORIZONTAL_TAB | constant::ascii::SPACE | constant::ascii::LINE_FEED | constant::ascii::CARRIAGE_RETURN)) => 1,
+            (Dialect::Json5, Some(constant::ascii::VERTICAL_TAB | constant::ascii::FORM_FEED)) => 1,
+            (Dialect::Json5, Some(0x80..)) => self.char_at(pos).filter(|it| is_unicode_whitespace(*it)).map_or(0, char::len_utf8),
+            _ => 0,
+        }
+    }
+
+    // parse all characters wrapped inside a pair of `quote`
+    fn next_str(&mut self, at: usize, quote: u8) -> JsonToken {
+        let mut str_tokens = Vec::<StrType>::new();
+        loop {
+            let Some(next_item) = self.next_item() else {
+                break JsonToken::error(ErrorKind::UnterminatedString(quote as char), at, self.pos);
+            };
+            if next_item.eq(&constant::ascii::ESCAPE)  {
+                let Some(next_it) = self.next_item() else {
+                    break JsonToken::error(ErrorKind::UnterminatedString(quote as char), at, self.pos);
                 };
-                if next_item.eq(&constant::ascii::ESCAPE)  {
-                    let Some(next_it) = self.next_item() else {
-                        break JsonToken::error(constant::msg::MISSING_DOUBLE_COLON, at, self.pos).into();
-                    };
-
-                    // handle '\xXX'
-                    if next_it.eq(&b'x') {
-                        match self.next_exact_until(2, |item| item.is_ascii_hexdigit()) {
-                            Ok(_) => {
-                                str_tokens.push(StrType::Ascii(Span::new(self.pos - 3, self.pos)));
-                                continue;
-                            },
-                            Err(err) => break JsonToken::from(err).into(),
-                        }
-                    }
 
-                    // handle '\uXXXX'
-                    if next_it.eq(&b'u') {
-                        match self.next_exact_until(4, |item| item.is_ascii_hexdigit()) {
-                            Ok(_) => {
-                                str_tokens.push(StrType::Unicode(Span::new(self.pos - 5, self.pos)));
-                                continue;
-                            },
-                            Err(err) => break JsonToken::from(err).into(),
-                        }
-                    }
+                // char = unescaped /
+                //     escape (
+                //         %x22 /          ; "    quotation mark  U+0022
+                //         %x5C /          ; \    reverse solidus U+005C
+                //         %x2F /          ; /    solidus         U+002F
+                //         %x62 /          ; b    backspace       U+0008
+                //         %x66 /          ; f    form feed       U+000C
+                //         %x6E /          ; n    line feed       U+000A
+                //         %x72 /          ; r    carriage return U+000D
+                //         %x74 /          ; t    tab             U+0009
+                //         %x75 4HEXDIG )  ; uXXXX                U+XXXX
+                if self.dialect.is_strict() && !matches!(next_it, b'"' | b'\\' | b'/' | b'b' | b'f' | b'n' | b'r' | b't' | b'u') {
+                    return JsonToken::error(ErrorKind::NotAllowed(constant::msg::STRICT_ESCAPE), self.pos - 2, self.pos);
+                }
 
-                    if matches!(next_it, b'\'' | b'\"' | b'\\' | b'b' | b'f' | b'n' | b'r' | b't' | b'v' | b'0') {
-                        str_tokens.push(StrType::Special(Span::new(self.pos - 1, self.pos)));
-                        continue;
-                    }
+                // line continuation (`\` followed by LF, CR, CRLF, U+2028 or U+2029), the line terminator is not part of the string
+                if next_it == constant::ascii::CARRIAGE_RETURN && self.peek_next_item() == Some(constant::ascii::LINE_FEED) {
+                    self.step_front();
+                }
+                let escaped = self.char_at(self.pos - 1).unwrap_or_default();
+                if matches!(escaped, '\n' | '\r' | constant::unicode::LINE_SEPARATOR | constant::unicode::PARAGRAPH_SEPARATOR) {
+                    self.pos += escaped.len_utf8() - 1;
+                    continue;
+                }
 
-                    if next_it.is_ascii_digit() {
-                        return JsonToken::error(format!("{}: {}", constant::msg::INVALID_ESCAPE, char::from_u32(next_it as u32).unwrap()), at, self.pos).into();
+                // handle '\xXX'
+                if next_it.eq(&b'x') {
+                    match self.next_exact_until(2, |item| item.is_ascii_hexdigit()) {
+                        Ok(_) => {
+                            str_tokens.push(StrType::Ascii(Span::new(self.pos - 4, self.pos)));
+                            continue;
+                        },
+                        Err(err) => break JsonToken::from(err),
                     }
+                }
 
-                    str_tokens.push(StrType::Escape(Span::new(self.pos - 1, self.pos)));
+                // handle '\uXXXX'
+                if next_it.eq(&b'u') {
+                    match self.next_exact_until(4, |item| item.is_ascii_hexdigit()) {
+                        Ok(_) => {
+                            str_tokens.push(StrType::Unicode(Span::new(self.pos - 6, self.pos)));
+                            continue;
+                        },
+                        Err(err) => break JsonToken::from(err),
+                    }
                 }
 
-                if next_item.eq(&constant::ascii::DOUBLE_QUOTE) {
-                    break JsonToken::str(str_tokens, at, self.pos).into()
+                // `\0` must not be followed by a decimal digit, like the other digits (no octal escape)
+                if next_it == b'0' && self.peek_next_item().is_some_and(|it| it.is_ascii_digit()) {
+                    return JsonToken::error(ErrorKind::InvalidEscape(String::from_utf8_lossy(self.take_raw(Span::new(self.pos - 2, self.pos + 1))).into_owned()), self.pos - 2, self.pos + 1);
                 }
 
-                let Span { start, end, .. } = self.move_backward_then_consume_until(1, |it| matches!(it, b'\\' | b'"'));
-                str_tokens.push(StrType::Str(Span::new(start, end)));
-            }},
-            // negative number
-            b'-' | b'+' => {
-                let Some(next_it) = self.peek_next_item() else {
-                    return JsonToken::error("Invalid number, '+' and '-' must follow by at least a digit", at, self.pos).into();
-                };
+                if matches!(next_it, b'\'' | b'\"' | b'\\' | b'b' | b'f' | b'n' | b'r' | b't' | b'v' | b'0') {
+                    str_tokens.push(StrType::Special(Span::new(self.pos - 1, self.pos)));
+                    continue;
+                }
 
-                if !next_it.is_ascii_digit() {
-                    return JsonToken::error("Invalid number, '+' and '-' must follow by at least a digit", at, self.pos).into();
+                if next_it.is_ascii_digit() {
+                    return JsonToken::error(ErrorKind::InvalidEscape(format!("\\{}", next_it as char)), self.pos - 2, self.pos);
                 }
 
-                JsonToken::whitespace(at, self.pos).into()
-            },
-            b'.' => {
-                // consume until reaching a non-digit character
-                let frac_span = self.consume_until(|item| !item.is_ascii_digit());
-                if frac_span.end != at {
-                    JsonToken::number(Decimal::Positive(None, Some(frac_span), None).into(), at, self.pos).into()
-                } else {
-                    JsonToken::error("Invalid decimal fracment", at, self.pos).into()
+                str_tokens.push(StrType::Escape(Span::new(self.pos - 1, self.pos)));
+            }
+
+            if next_item.eq(&quote) {
+                break JsonToken::str(str_tokens, at, self.pos)
+            }
+
+            let span = self.move_backward_then_consume_until(1, |it| it == constant::ascii::ESCAPE || it == quote);
+            // unescaped = %x20-21 / %x23-5B / %x5D-10FFFF
+            if self.dialect.is_strict() {
+                if let Some(offset) = self.take_raw(span.clone()).iter().position(|it| *it < constant::ascii::SPACE) {
+                    return JsonToken::error(ErrorKind::NotAllowed(constant::msg::STRICT_CONTROL_CHAR), span.start + offset, span.start + offset + 1);
                 }
-            },
-            // positive integer or decimal
-            b'0'..=b'9' => {
-                let Some(sign) = self.peek_prev_item() else {
-                    return JsonToken::error("None sense when parsing number, must have at least one token behind", at, self.pos).into();
-                };
-                let inverse = Sign::detect(sign);
-                let start_at = inverse.compute_start_pos(at);
-
-                // handle hexadecimal
-                if next_item.eq(&b'0') && self.peek_next_item().is_some_and(|it| matches!(it, b'x' | b'X')) {
-                    let _ = self.move_forward_then_consume_until(1, |item| !item.is_ascii_hexdigit());
-                    if (self.pos - at) < 4 {
-                        return JsonToken::error("Invalid hexadecimal, at least two hexdigit hehind 0x..", start_at, self.pos).into()
-                    } else {
-                        return JsonToken::number(inverse.to_hexadecimal(Span::new(at, at + 2), Span::new(at + 2, self.pos)), start_at, self.pos).into()
-                    }
+            }
+            str_tokens.push(StrType::Str(span));
+        }
+    }
+
+    // IdentifierName  = IdentifierStart *IdentifierPart
+    // IdentifierStart = UnicodeLetter / "$" / "_" / "\\" UnicodeEscapeSequence
+    // IdentifierPart  = IdentifierStart / UnicodeCombiningMark / UnicodeDigit / UnicodeConnectorPunctuation / ZWNJ / ZWJ
+    fn next_ident(&mut self, at: usize) -> JsonToken {
+        // read the identifier again from its first item
+        self.pos = at;
+        let mut escaped = false;
+        loop {
+            let is_valid = if self.pos == at { is_ident_start } else { is_ident_part };
+            if self.peek_next_item() == Some(constant::ascii::ESCAPE) {
+                // only `\uXXXX` is allowed, and it must stand for a valid character of the identifier
+                let escape = Span::new(self.pos, (self.pos + 6).min(self.size));
+                let raw = self.take_raw(escape.clone());
+                if !std::str::from_utf8(raw).ok().and_then(decode_unicode_escape).is_some_and(is_valid) {
+                    return JsonToken::error(ErrorKind::InvalidEscape(String::from_utf8_lossy(raw).into_owned()), escape.start, escape.end);
                 }
+                self.pos += 6;
+                escaped = true;
+                continue;
+            }
+            match self.char_at(self.pos) {
+                Some(c) if is_valid(c) => self.pos += c.len_utf8(),
+                _ => break,
+            }
+        }
 
-                let int_span = self.move_backward_then_consume_until(1, |item| !item.is_ascii_digit());
-                let Some(next_item) = self.next_item() else {
-                    return JsonToken::number(inverse.to_integer(at, self.pos, None), start_at, self.pos).into();
-                };
+        match (escaped, self.dialect) {
+            (true, Dialect::Strict) => JsonToken::error(ErrorKind::NotAllowed(constant::msg::STRICT_IDENT), at, self.pos),
+            // keywords never contain an escape sequence, `\u0074rue` is an identifier
+            (true, Dialect::Json5) => JsonToken::ident(at, self.pos),
+            (false, _) => self.parse_keyword(at),
+        }
+    }
 
-                // handle fractional
-                if next_item.eq(&b'.') {
-                    // consume until reaching a non-digit character
-                    let frac_span = self.consume_until(|item| !item.is_ascii_digit());
-
-                    if frac_span.start != frac_span.end {
-                        if self.peek_next_item().is_some_and(|it| matches!(it, b'e' | b'E')) {
-                            let expo_span = self.move_forward_then_consume_until(1, |item| !matches!(item, b'+' | b'-' | b'0'..=b'9'));
-                            if expo_span.start != expo_span.end {
-                                return JsonToken::number(inverse.to_decimal(Some(int_span), Some(frac_span), Some(expo_span)), start_at, self.pos).into();
-                            }
-                        }
-                        return JsonToken::number(inverse.to_decimal(Some(int_span), Some(frac_span), None), start_at, self.pos).into();
-                    } else {
-                        return JsonToken::number(inverse.to_decimal(Some(int_span), None, None), start_at, self.pos).into();
-                    }
+    // JSON5Number           = [ "+" / "-" ] ( "Infinity" / "NaN" / HexIntegerLiteral / DecimalLiteral )
+    // HexIntegerLiteral     = "0" ( "x" / "X" ) 1*HEXDIG
+    // DecimalLiteral        = DecimalIntegerLiteral "." *DIGIT [ ExponentPart ]
+    //                       / "." 1*DIGIT [ ExponentPart ]
+    //                       / DecimalIntegerLiteral [ ExponentPart ]
+    // DecimalIntegerLiteral = "0" / ( digit1-9 *DIGIT )
+    // ExponentPart          = ( "e" / "E" ) [ "+" / "-" ] 1*DIGIT
+    fn next_number(&mut self, at: usize) -> JsonToken {
+        let invalid = |reason: &str, end: usize| JsonToken::error(ErrorKind::InvalidNumber(reason.into()), at, end);
+        // read the number again from its first item, which may be a sign
+        self.pos = at;
+        let sign = self.peek_next_item().map_or(Sign::None, Sign::detect);
+        if !matches!(sign, Sign::None) {
+            self.step_front();
+        }
+        let start = self.pos;
+
+        let number = match self.peek_next_item() {
+            // signed keywords, the unsigned ones are lexed as identifiers
+            Some(b'I' | b'N') => {
+                let word = self.consume_until(|it| !is_ident_item(it));
+                match self.take_raw(word) {
+                    b"Infinity" | b"NaN" if self.dialect.is_strict() => return JsonToken::error(ErrorKind::NotAllowed(constant::msg::STRICT_KEYWORD), at, self.pos),
+                    b"Infinity" => NumType::Infinity(Span::new(at, self.pos)),
+                    b"NaN" => NumType::NaN(Span::new(at, self.pos)),
+                    _ => return invalid(constant::msg::LONELY_SIGN, self.pos),
                 }
-                // handle exponential
-                if matches!(next_item, b'e' | b'E') {
-                    // if exponent has a sign ('+' | '-') and follow by a digit. Move cursor to the next position and return 1.
-                    // if exponent has only digits, return 0 instead.
-                    // otherwise not a valid exponent
-                    let expo_sign: usize = match self.peek_next_item() {
-                        Some(b'+' | b'-') if self.peek_next_nth_item(2).is_some_and(|it| it.is_ascii_digit()) => {
-                            self.step_front();
-                            1
-                        },
-                        Some(n_n_it) if n_n_it.is_ascii_digit() => 0,
-                        _ => return JsonToken::error("Invalid exponential number, exponent must contain at least one number", start_at, self.pos).into(),
-                    };
-                    let Span { start: expo_start, end: expo_end, .. } = self.consume_until(|item| !item.is_ascii_digit());
-
-                    return JsonToken::number(
-                        // minus for the `expo_sign` which was computed in the previous step to include the sign ('+' | '-') in the final span
-                        // if it exist
-                        inverse.to_integer(int_span.start, int_span.end, Some(Span::new(expo_start - expo_sign, expo_end))),
-                        start_at,
-                        self.pos
-                    ).into();
+            },
+            Some(b'0') if matches!(self.peek_next_nth_item(2), Some(b'x' | b'X')) => {
+                let digits = self.move_forward_then_consume_until(2, |it| !it.is_ascii_hexdigit());
+                if digits.gap() == 0 {
+                    return invalid(constant::msg::EMPTY_HEXADECIMAL, self.pos);
                 }
-
-                self.step_back();
-                JsonToken::number(inverse.to_integer(at, self.pos, None), start_at, self.pos).into()
+                sign.to_hexadecimal(Span::new(start, start + 2), digits)
             },
-            // identity or keyword
-            b'a'..=b'z' | b'A'..=b'Z' | b'_' => loop {
-                let Some(next_item) = self.next_item() else {
-                    break self.parse_keyword(at).into()
+            Some(b'0'..=b'9' | b'.') => {
+                let int_span = self.consume_until(|it| !it.is_ascii_digit());
+                // the strict dialect reports it as a JSON5-only construct later on
+                if !self.dialect.is_strict() && int_span.gap() > 1 && self.take_raw(int_span.clone())[0] == b'0' {
+                    return invalid(constant::msg::LEADING_ZERO, self.pos);
+                }
+                let frac_span = (self.peek_next_item() == Some(b'.')).then(|| {
+                    self.step_front();
+                    self.consume_until(|it| !it.is_ascii_digit())
+                });
+                // a lonely `.` has neither integer nor fractional digits
+                if int_span.gap() == 0 && frac_span.as_ref().is_none_or(|it| it.gap() == 0) {
+                    return invalid(constant::msg::EMPTY_FRACTION, self.pos);
+                }
+                let expo_span = match self.peek_next_item() {
+                    Some(b'e' | b'E') => {
+                        self.step_front();
+                        // the exponent's span includes its sign
+                        let expo_start = self.pos;
+                        if matches!(self.peek_next_item(), Some(b'+' | b'-')) {
+                            self.step_front();
+                        }
+                        let digits = self.consume_until(|it| !it.is_ascii_digit());
+                        if digits.gap() == 0 {
+                            return invalid(constant::msg::EMPTY_EXPONENT, self.pos);
+                        }
+                        Some(Span::new(expo_start, digits.end))
+                    },
+                    _ => None,
                 };
-                // accept ident has number in their name such as 'u8', 'u16'
-                if !matches!(next_item, b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'_') {
-                    self.step_back();
-                    break Some(self.parse_keyword(at))
+                match frac_span {
+                    None => sign.to_integer(int_span.start, int_span.end, expo_span),
+                    Some(frac_span) => sign.to_decimal(
+                        (int_span.gap() > 0).then_some(int_span),
+                        (frac_span.gap() > 0).then_some(frac_span),
+                        expo_span,
+                    ),
                 }
             },
-            
-            unknown_token => JsonToken::error(format!("{} {}", constant::msg::NOT_SUPPORT_TOKEN, char::from_u32(unknown_token as u32).unwrap()), at, self.pos).into()
-        }
-    }
-}
-
-impl <'a> Tokenizer<'a> {
-    #[inline]
-    pub const fn take_raw(&self, span: Span) -> &[u8] {
-        unsafe { std::slice::from_raw_parts(self.ptr.add(span.start), span.gap()) }
-    }
+            _ => return invalid(constant::msg::LONELY_SIGN, self.pos),
+        };
 
-    #[inline]
-    pub fn take_slice(&self, span: Span) -> Result<&str, JsonError> {
-        unsafe {
-            let slice = std::slice::from_raw_parts(self.ptr.add(span.start), span.gap());
-            std::str::from_utf8(slice)
-                .map_err(|err| JsonError::custom(err.to_string(), span))
+        // a number must not run into an identifier or another number, such as `1a`, `0x1G` or `1.2.3`
+        if self.peek_next_item().is_some_and(|it| is_ident_item(it) || matches!(it, b'.' | b'$')) {
+            let _ = self.consume_until(|it| !is_ident_item(it) && !matches!(it, b'.' | b'$'));
+            return invalid(constant::msg::UNEXPECTED_CHAR, self.pos);
         }
+        JsonToken::number(number, at, self.pos)
     }
 
     // SAFETY: as long as `self.pos` being control and not exceeding `self.size`
@@ -317,18 +425,6 @@ impl <'a> Tokenizer<'a> {
         }
     }
 
-    // SAFETY: 0 <= self.pos <= self.size
-    #[inline]
-    const fn peek_prev_item(&self) -> Option<u8> {
-        if self.pos > 0 {
-            // subtract is sounded, program will panic if (self.pos - 2) < 0
-            let prev_item = unsafe { ptr::read(self.ptr.add(self.pos.saturating_sub(2))) };
-            Some(prev_item)
-        } else {
-            None
-        }
-    }
-
     #[inline]
     const fn peek_next_item(&self) -> Option<u8> {
         if self.pos >= self.size {
@@ -353,25 +449,35 @@ impl <'a> Tokenizer<'a> {
         }
     }
 
+    // consume exact `size` items satisfying `predicate`, used for the digits of `\xXX` and `\uXXXX`.
+    // On failure, the error cover the whole escape sequence from its backslash
     #[inline]
-    #[reflection]
     fn next_exact_until(&mut self, size: usize, predicate: impl Fn(u8) -> bool) -> Result<(), JsonError> {
-        for n in 0..size {
-            let Some(next_item) = self.next_item() else {
-                return Err(JsonError::custom(
-                    format!("[{__fn_ident}] soon EOF, expect {n} token more"),
-                    Span::new(self.pos - (self.pos - n + 1), self.pos)));
-            };
-
-            if !predicate(next_item) {
-                return Err(JsonError::custom(
-                    format!("[{__fn_ident}] cannot satisfy condition"),
-                    Span::new(self.pos - (self.pos - n + 1), self.pos)))
+        // step over the backslash and the escape letter
+        let start = self.pos - 2;
+        for _ in 0..size {
+            let valid = self.next_item().is_some_and(&predicate);
+            if !valid {
+                let escape = String::from_utf8_lossy(self.take_raw(Span::new(start, self.pos))).into_owned();
+                return Err(JsonError::new(ErrorKind::InvalidEscape(escape), Span::new(start, self.pos)))
             }
         }
         Ok(())
     }
 
+    // decode the character starting at `pos`, `None` if `pos` is not a character boundary
+    fn char_at(&self, pos: usize) -> Option<char> {
+        let raw = self.take_raw(Span::new(pos, self.size.min(pos + 4)));
+        let len = match raw.first()? {
+            0x00..=0x7F => 1,
+            0xC0..=0xDF => 2,
+            0xE0..=0xEF => 3,
+            0xF0..=0xF7 => 4,
+            _ => return None,
+        };
+        std::str::from_utf8(raw.get(..len)?).ok()?.chars().next()
+    }
+
     // iterate over `src` until reaching the **UNEXPECTED** token
     // CAUTION: this method modify `self.pos` to avoid consume the **UNEXPECTED** token
     #[inline]
@@ -393,11 +499,7 @@ impl <'a> Tokenizer<'a> {
     #[inline]
     fn consume_pair_until(&mut self, predicate: impl Fn(u8,u8) -> bool) -> Span {
         let start: usize = self.pos;
-        loop {
-            let (Some(first_item), Some(second_item)) = (self.next_item(), self.peek_next_item()) else {
-                break;
-            };
-
+        while let (Some(first_item), Some(second_item)) = (self.next_item(), self.peek_next_item()) {
             if predicate(first_item, second_item) {
                 // the cursor is pointing into `second_item`,
                 // move the cursor forward to consume it.
@@ -445,20 +547,115 @@ impl <'a> Tokenizer<'a> {
     fn parse_keyword(&self, start: usize) -> JsonToken {
         let gap = self.pos - start;
         let buf = unsafe { std::slice::from_raw_parts(self.ptr.add(start), gap) };
+        let strict = self.dialect.is_strict();
         std::str::from_utf8(buf).map(|res| match res {
             "true"      => JsonToken::boolean(true, start),
             "false"     => JsonToken::boolean(false, start),
+            "null"      => JsonToken::null(start),
+            "Infinity" | "NaN" if strict => JsonToken::error(ErrorKind::NotAllowed(constant::msg::STRICT_KEYWORD), start, self.pos),
             "Infinity"  => JsonToken::number(NumType::Infinity(Span::new(start, self.pos)), start, self.pos),
             "NaN"       => JsonToken::number(NumType::NaN(Span::new(start, self.pos)), start, self.pos),
+            _ if strict => JsonToken::error(ErrorKind::NotAllowed(constant::msg::STRICT_IDENT), start, self.pos),
             _           => JsonToken::ident(start, self.pos),
-        }).unwrap_or_else(|err| JsonToken::error(err.to_string(), start, gap))
+        }).unwrap_or_else(|err| JsonToken::error(ErrorKind::InvalidUtf8(err.to_string()), start, self.pos))
         
     }
 }
 
+/// JSON5 `IdentifierStart`, Unicode letters are approximated by [`char::is_alphabetic`]
+#[inline]
+pub(crate) fn is_ident_start(c: char) -> bool {
+    c.is_alphabetic() || matches!(c, '$' | '_')
+}
+
+/// JSON5 `IdentifierPart`, combining marks and digits are approximated by [`char::is_alphanumeric`]
+#[inline]
+pub(crate) fn is_ident_part(c: char) -> bool {
+    c.is_alphanumeric() || matches!(c, '$' | '_' | constant::unicode::ZERO_WIDTH_NON_JOINER | constant::unicode::ZERO_WIDTH_JOINER)
+        || matches!(c, '\u{203F}' | '\u{2040}' | '\u{2054}' | '\u{FE33}' | '\u{FE34}' | '\u{FE4D}'..='\u{FE4F}' | '\u{FF3F}')
+}
+
+// WhiteSpace of JSON5 beyond ASCII
+#[inline]
+const fn is_unicode_whitespace(c: char) -> bool {
+    matches!(c, constant::unicode::NON_BREAKING_SPACE
+        | constant::unicode::LINE_SEPARATOR
+        | constant::unicode::PARAGRAPH_SEPARATOR
+        | constant::unicode::BYTE_ORDER_MARK
+        // space separators (Zs)
+        | '\u{1680}' | '\u{2000}'..='\u{200A}' | '\u{202F}' | '\u{205F}' | '\u{3000}')
+}
+
+// `\uXXXX` into its character, `None` for anything else (including a lonely surrogate)
+fn decode_unicode_escape(escape: &str) -> Option<char> {
+    let digits = escape.strip_prefix("\\u").filter(|it| it.len() == 4 && it.bytes().all(|it| it.is_ascii_hexdigit()))?;
+    char::from_u32(u32::from_str_radix(digits, 16).ok()?)
+}
+
+/// Decode the `\uXXXX` escapes of an identifier validated by the lexer, `None` when it has none.
+pub(crate) fn unescape_ident(ident: &str) -> Option<Box<str>> {
+    if !ident.contains('\\') {
+        return None;
+    }
+    let mut decoded = String::with_capacity(ident.len());
+    let mut rest = ident;
+    while let Some(at) = rest.find('\\') {
+        decoded.push_str(&rest[..at]);
+        decoded.extend(rest.get(at..at + 6).and_then(decode_unicode_escape));
+        rest = rest.get(at + 6..).unwrap_or_default();
+    }
+    decoded.push_str(rest);
+    Some(decoded.into_boxed_str())
+}
+
+#[inline]
+const fn is_ident_item(item: u8) -> bool {
+    matches!(item, b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'_')
+}
+
+// number = [ minus ] int [ frac ] [ exp ]
+// int    = zero / ( digit1-9 *DIGIT )
+// frac   = decimal-point 1*DIGIT
+// exp    = e [ minus / plus ] 1*DIGIT
+fn check_strict_number(raw: &[u8]) -> Result<(), ErrorKind> {
+    let digits = |pos: &mut usize| {
+        let start = *pos;
+        while raw.get(*pos).is_some_and(u8::is_ascii_digit) { *pos += 1; }
+        *pos - start
+    };
+    let mut pos = usize::from(raw.first() == Some(&b'-'));
+
+    match raw.get(pos) {
+        Some(b'0') if raw.get(pos + 1).is_some_and(u8::is_ascii_digit) => return Err(ErrorKind::NotAllowed(constant::msg::STRICT_LEADING_ZERO)),
+        Some(b'0'..=b'9') => { digits(&mut pos); },
+        _ => return Err(ErrorKind::NotAllowed(constant::msg::STRICT_LEADING_DOT)),
+    }
+
+    if raw.get(pos) == Some(&b'.') {
+        pos += 1;
+        if digits(&mut pos) == 0 {
+            return Err(ErrorKind::InvalidNumber(constant::msg::EMPTY_FRACTION.into()));
+        }
+    }
+
+    if matches!(raw.get(pos), Some(b'e' | b'E')) {
+        pos += 1;
+        if matches!(raw.get(pos), Some(b'+' | b'-')) { pos += 1; }
+        if digits(&mut pos) == 0 {
+            return Err(ErrorKind::InvalidNumber(constant::msg::EMPTY_EXPONENT.into()));
+        }
+    }
+
+    if pos != raw.len() {
+        return Err(ErrorKind::InvalidNumber(constant::msg::UNEXPECTED_DIGIT.into()));
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::core::Integer;
+    use crate::core::{Decimal, Heximal, Integer};
     use super::*;
 
     #[test]
@@ -487,6 +684,16 @@ mod tests {
         assert_eq!(None                              , kws.next());
     }
 
+    #[test]
+    pub fn parse_null_keyword() {
+        let mut kws = Tokenizer::from("null nullable");
+
+        assert_eq!(Some(JsonToken::null(0))          , kws.next());
+        assert_eq!(Some(JsonToken::whitespace(4,5))  , kws.next());
+        assert_eq!(Some(JsonToken::ident(5,13))      , kws.next());
+        assert_eq!(None                              , kws.next());
+    }
+
     #[test]
     pub fn parse_str_missing_() {
         let mut errors = Tokenizer::from("{ totally_error_str: 'abc }");
@@ -496,10 +703,59 @@ mod tests {
         assert_eq!(Some(JsonToken::ident(2, 19))   , errors.next());
         assert_eq!(Some(JsonToken::colon(19))      , errors.next());
         assert_eq!(Some(JsonToken::whitespace(20,21)), errors.next());
-        assert_eq!(Some(JsonToken::error(constant::msg::MISSING_SINGLE_COLON, 21, 27)), errors.next());
+        assert_eq!(Some(JsonToken::error(ErrorKind::UnterminatedString('\''), 21, 27)), errors.next());
         assert_eq!(None                            , errors.next());
     }
 
+    #[test]
+    fn track_line_and_column() {
+        let tokens = Tokenizer::from("{\n  a: 'é',\r\n  b: 1\n}");
+        let located = tokens
+            .filter(|tk| !matches!(tk, JsonToken::Punct(crate::core::Punct::WhiteSpace, _)))
+            .map(|tk| { let span = tk.get_span(); (span.row, span.col) })
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec![(1,1), (2,3), (2,4), (2,6), (2,9), (3,3), (3,4), (3,6), (4,1)], located);
+    }
+
+    #[test]
+    fn signed_number_covers_its_sign() {
+        let tokens = Tokenizer::from("-0xF +.5 -1e3 5. -NaN").filter(|it| !matches!(it, JsonToken::Punct(_, _))).collect::<Vec<_>>();
+
+        assert_eq!(vec![
+            JsonToken::number(Heximal::Negative(Span::new(0, 3), Span::new(3, 4)).into(), 0, 4),
+            JsonToken::number(Decimal::Positive(None, Some(Span::new(7, 8)), None).into(), 5, 8),
+            JsonToken::number(Integer::Negative(Span::new(9, 11), Some(Span::new(12, 13))).into(), 9, 13),
+            JsonToken::number(Decimal::Positive(Some(Span::new(14, 15)), None, None).into(), 14, 16),
+            JsonToken::number(NumType::NaN(Span::new(17, 21)), 17, 21),
+        ], tokens);
+    }
+
+    #[test]
+    fn unicode_whitespace_and_identifiers() {
+        let tokens = Tokenizer::from("\u{FEFF}$a\u{A0}café\u{2028}\\u0061b\u{3000}_1\u{200C}").collect::<Vec<_>>();
+
+        assert_eq!(vec![
+            JsonToken::whitespace(0, 3),
+            JsonToken::ident(3, 5),
+            JsonToken::whitespace(5, 7),
+            JsonToken::ident(7, 12),
+            JsonToken::whitespace(12, 15),
+            JsonToken::ident(15, 22),
+            JsonToken::whitespace(22, 25),
+            JsonToken::ident(25, 30),
+        ], tokens);
+        assert_eq!(Some("ab".into()), unescape_ident("\\u0061b"));
+        assert_eq!(None, unescape_ident("plain"));
+    }
+
+    #[test]
+    fn invalid_identifier_escape() {
+        let mut tokens = Tokenizer::from("\\u0031a");
+
+        assert!(matches!(tokens.next(), Some(JsonToken::Error(ErrorKind::InvalidEscape(_), _))));
+    }
+
     #[test]
     fn peek_iter() {
         let src = "abcde";
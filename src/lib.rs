@@ -1,7 +1,11 @@
 pub(crate) mod core;
 pub(crate) mod error;
+pub(crate) mod diagnostic;
 pub(crate) mod deserialize;
+pub(crate) mod serialize;
 pub(crate) mod indexer;
+pub(crate) mod option;
+pub(crate) mod number;
 
 pub mod common;
 pub(crate) mod constant;
@@ -10,16 +14,24 @@ pub(crate) mod parser;
 
 pub mod prelude {
     pub use crate::core::{JsonOutput, Span, Result,};
-    pub use crate::error::JsonError;
+    pub use crate::error::{ErrorKind, JsonError, JsonPath, PathSegment,};
+    pub use crate::diagnostic::Diagnostic;
     pub use crate::parser::JsonParser;
-    pub use crate::deserialize::{Deserialize, JsonPsr,};
+    pub use crate::deserialize::{Deserialize, JsonPsr, JsonArrayView,};
+    pub use crate::serialize::{Serialize, JsonWriter, WriterOptions, IoWriter, Quote, Hex,};
     pub use crate::indexer::JsonIdx;
+    pub use crate::option::{Dialect, DuplicateKey, ParserOptions,};
+    pub use crate::number::JsonNumber;
     #[cfg(feature = "macro")]
-    pub use jsode_macro::Deserialize;
+    pub use jsode_macro::{Deserialize, Serialize,};
 }
 
 pub use crate::core::{JsonOutput, Span, Result,};
-pub use crate::error::JsonError;
+pub use crate::error::{ErrorKind, JsonError, JsonPath, PathSegment,};
+pub use crate::diagnostic::Diagnostic;
 pub use crate::parser::JsonParser;
-pub use crate::deserialize::{Deserialize, JsonPsr,};
-pub use crate::indexer::JsonIdx;
\ No newline at end of file
+pub use crate::deserialize::{Deserialize, JsonPsr, JsonArrayView, parse_object_to_map,};
+pub use crate::serialize::{Serialize, JsonWriter, WriterOptions, IoWriter, Quote, Hex,};
+pub use crate::indexer::JsonIdx;
+pub use crate::option::{Dialect, DuplicateKey, ParserOptions,};
+pub use crate::number::JsonNumber;
@@ -19,6 +19,9 @@ fn main() {
 
     match JsonParser::new(&s).parse() {
         Ok(_) => std::process::exit(0),
-        _ => std::process::exit(1),
+        Err(err) => {
+            eprintln!("{}", err.diagnostic(&s).with_file_name(path).render_ansi());
+            std::process::exit(1)
+        },
     }
 }
\ No newline at end of file
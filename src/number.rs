@@ -0,0 +1,241 @@
+use std::{fmt::{self, Display, Write}, str::FromStr};
+
+use crate::{
+    core::{JsonOutput, JsonType, JsonValue},
+    deserialize::Deserialize,
+    error::{ErrorKind, JsonError},
+    serialize::{JsonWriter, Serialize},
+    Span,
+};
+
+/// A number kept as its exact source text, such as `1e400`, `-0x80` or `3.14159265358979323846264338327950288`.
+///
+/// Nothing is lost to a conversion, so the text can be handed to an arbitrary-precision library.
+///
+/// ```
+/// use jsode::prelude::*;
+///
+/// let mut src = JsonParser::new("{ price: 19.999999999999999999 }");
+/// let price = src.parse()?.index("price").unwrap().parse_into::<JsonNumber>()?;
+/// assert_eq!("19.999999999999999999", price.as_str());
+/// assert!(!price.is_integer());
+/// # Ok::<(), JsonError>(())
+/// ```
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub struct JsonNumber<'src>(&'src str);
+
+impl <'src> JsonNumber<'src> {
+    #[inline]
+    pub const fn as_str(&self) -> &'src str {
+        self.0
+    }
+
+    /// written without fraction nor exponent, hexadecimal included
+    pub fn is_integer(&self) -> bool {
+        let unsigned = self.0.trim_start_matches(['+', '-']);
+        is_hex(unsigned) || unsigned.bytes().all(|it| it.is_ascii_digit())
+    }
+
+    #[inline]
+    pub fn is_negative(&self) -> bool {
+        self.0.starts_with('-')
+    }
+
+    /// convert the text with [`FromStr`], e.g. into a big decimal type
+    #[inline]
+    pub fn parse<T: FromStr>(&self) -> Result<T, T::Err> {
+        self.0.parse()
+    }
+}
+
+impl Display for JsonNumber<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.0)
+    }
+}
+
+impl AsRef<str> for JsonNumber<'_> {
+    fn as_ref(&self) -> &str {
+        self.0
+    }
+}
+
+impl <'a, 'src: 'a> Deserialize<'src> for JsonNumber<'a> {
+    fn parse(out: &JsonOutput<'_, 'src>) -> Result<Self, JsonError> {
+        match out.ast.as_slice().first().map(|it| &it.value) {
+            Some(JsonValue::Value(JsonType::Num(_), _) | JsonValue::Prop(JsonType::Num(_),_,_)) => out.to_slice().map(JsonNumber),
+            Some(other_type) => Err(JsonError::type_mismatch("JsonNumber", other_type.get_type_name(), other_type.get_span())),
+            None => Err(JsonError::unexpected_eof("value", Span::default())),
+        }
+    }
+}
+
+// the text is written as it is, except the JSON5-only forms (`+1`, `.5`, `0x1F`, `Infinity`, ..) in plain JSON
+impl Serialize for JsonNumber<'_> {
+    fn serialize<W: Write>(&self, writer: &mut JsonWriter<W>) -> fmt::Result {
+        if writer.options().dialect().is_strict() && !is_plain_json(self.0) {
+            return match integral(self.0, false) {
+                Ok(Integral { negative: true, magnitude }) => writer.write_integer(format_args!("-{magnitude}")),
+                Ok(Integral { magnitude, .. }) => writer.write_integer(magnitude),
+                Err(_) => writer.write_float(self.0.parse().map_err(|_| fmt::Error)?),
+            };
+        }
+        writer.write_integer(self.0)
+    }
+}
+
+#[inline]
+fn is_hex(unsigned: &str) -> bool {
+    unsigned.starts_with("0x") || unsigned.starts_with("0X")
+}
+
+// `-?(0|[1-9][0-9]*)(\.[0-9]+)?([eE][+-]?[0-9]+)?` from RFC 8259
+fn is_plain_json(text: &str) -> bool {
+    let unsigned = text.strip_prefix('-').unwrap_or(text);
+    let (mantissa, exponent) = unsigned.split_once(['e', 'E']).unwrap_or((unsigned, "0"));
+    let (int, frac) = mantissa.split_once('.').unwrap_or((mantissa, "0"));
+    let digits = |part: &str| !part.is_empty() && part.bytes().all(|it| it.is_ascii_digit());
+    digits(int) && (int == "0" || !int.starts_with('0'))
+        && digits(frac)
+        && digits(exponent.strip_prefix(['+', '-']).unwrap_or(exponent))
+}
+
+/// sign and absolute value of a number converted into an integer
+#[derive(PartialEq, Debug)]
+pub(crate) struct Integral {
+    pub(crate) negative: bool,
+    pub(crate) magnitude: u128,
+}
+
+#[derive(PartialEq, Debug)]
+pub(crate) enum NotIntegral {
+    /// the number has a fractional part
+    Fraction,
+    /// the absolute value does not fit in `u128`
+    Overflow,
+    /// `Infinity` or `NaN`
+    NonFinite,
+}
+
+/// Exact integer value of a number's text (as validated by the lexer), applying its exponent.
+///
+/// `1e3` is `1000` and `2.50e1` is `25`, while `1.5` is rejected unless `lossy` is set,
+/// which truncates the fractional part toward zero.
+pub(crate) fn integral(text: &str, lossy: bool) -> Result<Integral, NotIntegral> {
+    let negative = text.starts_with('-');
+    let unsigned = text.trim_start_matches(['+', '-']);
+    if is_hex(unsigned) {
+        // only overflow is possible on the hex digits
+        return u128::from_str_radix(&unsigned[2..], 16)
+            .map(|magnitude| Integral { negative, magnitude })
+            .map_err(|_| NotIntegral::Overflow);
+    }
+    if !unsigned.starts_with(|it: char| it.is_ascii_digit() || it == '.') {
+        return Err(NotIntegral::NonFinite);
+    }
+
+    let (mantissa, exponent) = unsigned.split_once(['e', 'E']).unwrap_or((unsigned, "0"));
+    let (int, frac) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    // an exponent out of `i64` saturates, its number is either zero or out of range anyway
+    let exponent = exponent.parse::<i64>().unwrap_or(if exponent.starts_with('-') { i64::MIN } else { i64::MAX });
+    // power of ten applied on all digits (`int` followed by `frac`)
+    let scale = exponent.saturating_sub(frac.len() as i64);
+
+    let digits = int.bytes().chain(frac.bytes()).skip_while(|it| *it == b'0');
+    let count = digits.clone().count() as i64;
+    // digits in front of the decimal point once the scale is applied, then the trailing zeros
+    let (kept, zeros) = if scale >= 0 { (count, scale) } else { (count.saturating_add(scale).max(0), 0) };
+    if !lossy && digits.clone().skip(kept as usize).any(|it| it != b'0') {
+        return Err(NotIntegral::Fraction);
+    }
+
+    let mut magnitude = 0u128;
+    for digit in digits.take(kept as usize) {
+        magnitude = magnitude.checked_mul(10)
+            .and_then(|it| it.checked_add(u128::from(digit - b'0')))
+            .ok_or(NotIntegral::Overflow)?;
+    }
+    // a non-zero magnitude overflows after at most 39 rounds
+    if magnitude != 0 {
+        for _ in 0..zeros {
+            magnitude = magnitude.checked_mul(10).ok_or(NotIntegral::Overflow)?;
+        }
+    }
+    Ok(Integral { negative, magnitude })
+}
+
+/// Deserialize a number into an integer type `T`, `signed` tells whether `T` accepts negative numbers.
+pub(crate) fn parse_integer<T: TryFrom<u128> + TryFrom<i128>>(out: &JsonOutput<'_, '_>, ty: &'static str, signed: bool) -> Result<T, JsonError> {
+    let (num, span) = match out.ast.as_slice().first().map(|it| &it.value) {
+        Some(JsonValue::Value(JsonType::Num(num), span) | JsonValue::Prop(JsonType::Num(num), span, _)) => (num, span),
+        Some(other_type) => return Err(JsonError::type_mismatch(ty, other_type.get_type_name(), other_type.get_span())),
+        None => return Err(JsonError::unexpected_eof("value", Span::default())),
+    };
+    let text = out.to_slice()?;
+    let overflow = || JsonError::new(ErrorKind::IntegerOverflow(ty), span.clone());
+    match integral(text, out.parser.options().lossy_integers()) {
+        Ok(Integral { negative: false, magnitude }) | Ok(Integral { negative: true, magnitude: magnitude @ 0 }) => T::try_from(magnitude).map_err(|_| overflow()),
+        Ok(Integral { negative: true, .. }) if !signed => Err(JsonError::type_mismatch(ty, format!("negative {}", num.get_type_name()), span.clone())),
+        // `0 - magnitude` reaches `i128::MIN`, which has no positive counterpart
+        Ok(Integral { negative: true, magnitude }) => 0i128.checked_sub_unsigned(magnitude)
+            .and_then(|it| T::try_from(it).ok())
+            .ok_or_else(overflow),
+        Err(NotIntegral::Overflow) => Err(overflow()),
+        Err(NotIntegral::Fraction) => Err(JsonError::new(ErrorKind::InvalidValue { ty, reason: format!("`{text}` has a fractional part") }, span.clone())),
+        Err(NotIntegral::NonFinite) => Err(JsonError::type_mismatch(ty, num.get_type_name(), span.clone())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{integral, is_plain_json, Integral, NotIntegral};
+
+    fn exact(text: &str) -> Result<Integral, NotIntegral> {
+        integral(text, false)
+    }
+
+    #[test]
+    fn integral_value() {
+        let positive = |magnitude| Ok(Integral { negative: false, magnitude });
+        assert_eq!(positive(1000), exact("1e3"));
+        assert_eq!(positive(1000), exact("1E+3"));
+        assert_eq!(positive(25), exact("2.50e1"));
+        assert_eq!(positive(15), exact("150e-1"));
+        assert_eq!(positive(7), exact("+7.000"));
+        assert_eq!(positive(0), exact("0.0e999999999999999999999"));
+        assert_eq!(positive(0xFF), exact("0xff"));
+        assert_eq!(Ok(Integral { negative: true, magnitude: 0x80 }), exact("-0x80"));
+        assert_eq!(Ok(Integral { negative: true, magnitude: 0 }), exact("-0"));
+    }
+
+    #[test]
+    fn not_integral() {
+        assert_eq!(Err(NotIntegral::Fraction), exact("1.5"));
+        assert_eq!(Err(NotIntegral::Fraction), exact("15e-1"));
+        assert_eq!(Err(NotIntegral::Fraction), exact("1e-999999999999999999999"));
+        assert_eq!(Err(NotIntegral::Overflow), exact("1e39"));
+        assert_eq!(Err(NotIntegral::Overflow), exact("1e999999999999999999999"));
+        assert_eq!(Err(NotIntegral::Overflow), exact("0x1000000000000000000000000000000000"));
+        assert_eq!(Err(NotIntegral::NonFinite), exact("-Infinity"));
+        assert_eq!(Err(NotIntegral::NonFinite), exact("NaN"));
+    }
+
+    #[test]
+    fn lossy_integral() {
+        assert_eq!(Ok(Integral { negative: false, magnitude: 1 }), integral("1.99", true));
+        assert_eq!(Ok(Integral { negative: true, magnitude: 0 }), integral("-.5", true));
+        assert_eq!(Ok(Integral { negative: false, magnitude: 0 }), integral("1e-999999999999999999999", true));
+    }
+
+    #[test]
+    fn plain_json() {
+        assert!(is_plain_json("-12.5e+3"));
+        assert!(is_plain_json("0"));
+        assert!(!is_plain_json("+1"));
+        assert!(!is_plain_json(".5"));
+        assert!(!is_plain_json("5."));
+        assert!(!is_plain_json("012"));
+        assert!(!is_plain_json("0x1F"));
+        assert!(!is_plain_json("Infinity"));
+    }
+}
@@ -0,0 +1,95 @@
+/// The JSON flavour accepted by [`JsonParser`](crate::parser::JsonParser)
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Default)]
+pub enum Dialect {
+    /// plain [RFC 8259](https://www.rfc-editor.org/rfc/rfc8259) JSON, every JSON5-only construct is rejected
+    Strict,
+    /// the [JSON5](https://spec.json5.org/) superset (single quotes, unquoted keys, hexadecimal,
+    /// `Infinity`/`NaN`, comments, trailing commas, leading `+`)
+    #[default]
+    Json5,
+}
+
+impl Dialect {
+    #[inline(always)]
+    pub const fn is_strict(&self) -> bool {
+        matches!(self, Self::Strict)
+    }
+}
+
+/// What [`JsonParser`](crate::parser::JsonParser) does when an object repeats a key
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Default)]
+pub enum DuplicateKey {
+    /// reject the object with [`ErrorKind::DuplicateKey`](crate::error::ErrorKind::DuplicateKey),
+    /// which reports the spans of both occurrences
+    Error,
+    /// keep the first occurrence, the later ones are ignored
+    First,
+    /// keep the last occurrence, the earlier ones are ignored
+    #[default]
+    Last,
+    /// keep every occurrence, see [`JsonOutput::get_all`](crate::core::JsonOutput::get_all)
+    All,
+}
+
+/// Options controlling how [`JsonParser`](crate::parser::JsonParser) reads its input.
+///
+/// ```
+/// use jsode::prelude::*;
+///
+/// let mut parser = JsonParser::with_options("{ 'a': 1 }", ParserOptions::strict());
+/// assert!(parser.parse().is_err());
+/// ```
+#[derive(PartialEq, Eq, Clone, Debug, Default)]
+pub struct ParserOptions {
+    pub(crate) dialect: Dialect,
+    pub(crate) duplicate_key: DuplicateKey,
+    pub(crate) lossy_integers: bool,
+}
+
+impl ParserOptions {
+    #[inline]
+    pub const fn new() -> Self {
+        Self { dialect: Dialect::Json5, duplicate_key: DuplicateKey::Last, lossy_integers: false }
+    }
+
+    /// shortcut for `ParserOptions::new().with_dialect(Dialect::Strict)`
+    #[inline]
+    pub const fn strict() -> Self {
+        Self::new().with_dialect(Dialect::Strict)
+    }
+
+    #[inline]
+    pub const fn with_dialect(mut self, dialect: Dialect) -> Self {
+        self.dialect = dialect;
+        self
+    }
+
+    #[inline]
+    pub const fn dialect(&self) -> Dialect {
+        self.dialect
+    }
+
+    #[inline]
+    pub const fn with_duplicate_key(mut self, duplicate_key: DuplicateKey) -> Self {
+        self.duplicate_key = duplicate_key;
+        self
+    }
+
+    #[inline]
+    pub const fn duplicate_key(&self) -> DuplicateKey {
+        self.duplicate_key
+    }
+
+    /// a number with a fractional part (`1.9`, `15e-1`) deserialized into an integer type
+    /// is truncated toward zero instead of rejected, out of range numbers are still an error
+    #[inline]
+    pub const fn with_lossy_integers(mut self, lossy_integers: bool) -> Self {
+        self.lossy_integers = lossy_integers;
+        self
+    }
+
+    #[inline]
+    pub const fn lossy_integers(&self) -> bool {
+        self.lossy_integers
+    }
+}
@@ -1,49 +1,70 @@
-use std::collections::{HashMap, VecDeque};
+use std::collections::VecDeque;
 
 use jsode_macro::reflection;
 
 use crate::{
-    common, constant::msg, core::{
+    common::KeyHasher, constant::msg, core::{
         JsonBlock, JsonOutput, JsonToken,
-        JsonType, JsonValue, Punct, Span,
-    }, error::JsonError, lexer::Tokenizer
+        JsonType, JsonValue, NumType, PropIndexes, Punct, Span,
+    }, deserialize::{borrow_str, parse_str}, error::{ErrorKind, JsonError}, lexer::{unescape_ident, Tokenizer}, option::ParserOptions
 };
 
 #[derive(PartialEq, Debug)]
 pub struct JsonParser<'tk> {
     iter: Tokenizer<'tk>,
+    options: ParserOptions,
+    hasher: KeyHasher,
 }
 
 impl<'tk> JsonParser<'tk> {
     #[inline]
     pub fn new(src: &'tk str) -> Self {
+        Self::with_options(src, ParserOptions::default())
+    }
+
+    #[inline]
+    pub fn with_options(src: &'tk str, options: ParserOptions) -> Self {
         Self {
-            iter: Tokenizer::from(src),
+            iter: Tokenizer::from(src).with_dialect(options.dialect),
+            options,
+            hasher: KeyHasher::default(),
         }
     }
+
+    #[inline]
+    pub const fn options(&self) -> &ParserOptions {
+        &self.options
+    }
+
+    #[inline]
+    pub(crate) const fn hasher(&self) -> &KeyHasher {
+        &self.hasher
+    }
 }
 
 impl<'tk> JsonParser<'tk> {
-    pub fn parse(&'_ mut self) -> crate::Result<JsonOutput<'_>> {
+    pub fn parse(&'_ mut self) -> crate::Result<JsonOutput<'_, 'tk>> {
         let mut cursor = JsonCursor::init(self)?;
 
         let init_block = match cursor.roots.back() {
-            Some(State::Object(_, _)) => JsonBlock::new(0, JsonValue::Object(HashMap::with_capacity(10), Span::default())),
+            Some(State::Object(_, _)) => JsonBlock::new(0, JsonValue::Object(PropIndexes::with_capacity(10), Span::default())),
             Some(State::Array(_, _)) => JsonBlock::new(0, JsonValue::Array(Vec::with_capacity(10), Span::default())),
-            Some(State::Value(JsonType::Ident, span)) => return Err(JsonError::custom("Invalid JSON", span.clone())),
-            Some(State::Value(_, value_span)) => {
-                if self.next_token().is_some() {
-                    return Err(JsonError::custom("Invalid JSON", value_span.clone()));
+            Some(State::Value(JsonType::Ident, span)) => return Err(JsonError::unexpected_token("value", JsonType::Ident.get_type_name(), span.clone())),
+            Some(State::Value(_, _)) => {
+                match self.next_token_skip(|tk| matches!(tk, JsonToken::Punct(Punct::WhiteSpace, _) | JsonToken::Comment(_))) {
+                    Some(JsonToken::Error(kind, span)) => return Err(JsonError::new(kind, span)),
+                    Some(token) => return Err(JsonError::new(ErrorKind::TrailingData, token.get_span())),
+                    None => (),
                 }
-                let State::Value(ty, span) = cursor.roots.pop_back().unwrap() else {
-                    return Err(JsonError::custom("Invalid JSON", Span::default())); 
+                let Some(State::Value(ty, span)) = cursor.roots.pop_back() else {
+                    return Err(JsonError::custom(msg::SOON_EOS, Span::default()));
                 };
 
                 return Ok(JsonOutput::new(self, Vec::<JsonBlock>::from_iter([
                     JsonBlock::new(0, JsonValue::Value(ty, span))
                 ])));
             },
-            None => return Err(JsonError::custom("Invalid JSON", Span::default())),
+            None => return Err(JsonError::custom(msg::SOON_EOS, Span::default())),
         };
         cursor.level += 1;
 
@@ -56,26 +77,28 @@ impl<'tk> JsonParser<'tk> {
                 State::Value(_,_) => cursor.parse_value(self),
             }?;
 
-            let next_token = match (!cursor.roots.is_empty(), self.next_token()) {
+            let next_token = match (!cursor.roots.is_empty(), self.next_token_skip(|tk| matches!(tk, JsonToken::Punct(Punct::WhiteSpace, _) | JsonToken::Comment(_)))) {
+                (_, Some(JsonToken::Error(kind, span))) => return Err(JsonError::new(kind, span)),
                 (true, token @ Some(_)) => token,
                 (false, None) => None,
-                _ => return Err(JsonError::custom("Invalid JSON", Span::default())),
+                (false, Some(token)) => return Err(JsonError::new(ErrorKind::TrailingData, token.get_span())),
+                (true, None) => return Err(JsonError::unexpected_eof("',' or closing bracket", self.eof_span())),
             };
 
-            let Some(block_value) = block else { match &next_token {
-                Some(JsonToken::Punct(Punct::Comma, _)) => 0,
-                None => 0,
-                Some(JsonToken::Punct(Punct::CloseCurly | Punct::CloseSquare, span)) => self.iter.step_back_nth(span.gap()),
-                Some(other) => return Err(JsonError::custom("expect comma or close-square, found other", other.get_span())),
+            let Some(block_value) = block else { match next_token {
+                Some(JsonToken::Punct(Punct::Comma, span)) => cursor.comma = Some(span),
+                None => (),
+                Some(JsonToken::Punct(Punct::CloseCurly | Punct::CloseSquare, span)) => { self.iter.step_back_nth(span.gap()); },
+                Some(other) => return Err(JsonError::unexpected_token("',' or closing bracket", other.describe(), other.get_span())),
             }; continue; };
 
             match (&block_value.value, next_token) {
-                (JsonValue::Prop(_,_,_) | JsonValue::Value(_,_), Some(JsonToken::Punct(Punct::Comma, _))) => 0,
-                (JsonValue::Prop(_,_,_) | JsonValue::Value(_,_), Some(JsonToken::Punct(Punct::CloseCurly | Punct::CloseSquare, span))) => self.iter.step_back_nth(span.gap()),
-                (JsonValue::Prop(_,_,_) | JsonValue::Value(_,_), Some(other)) => return Err(JsonError::custom("expect comma or close-square, found other", other.get_span())),
-                (JsonValue::Prop(_,_,_) | JsonValue::Value(_,_), None) => return Err(JsonError::custom("parsing prop value but reaching None", Span::default())),
-                (_, Some(other)) => self.iter.step_back_nth(other.get_span().gap()),
-                (_, None) => return Err(JsonError::custom("expect comma or close-square, found other", Span::default())),
+                (JsonValue::Prop(_,_,_) | JsonValue::Value(_,_), Some(JsonToken::Punct(Punct::Comma, span))) => cursor.comma = Some(span),
+                (JsonValue::Prop(_,_,_) | JsonValue::Value(_,_), Some(JsonToken::Punct(Punct::CloseCurly | Punct::CloseSquare, span))) => { self.iter.step_back_nth(span.gap()); },
+                (JsonValue::Prop(_,_,_) | JsonValue::Value(_,_), Some(other)) => return Err(JsonError::unexpected_token("',' or closing bracket", other.describe(), other.get_span())),
+                (JsonValue::Prop(_,_,_) | JsonValue::Value(_,_), None) => return Err(JsonError::unexpected_eof("',' or closing bracket", self.eof_span())),
+                (_, Some(other)) => { self.iter.step_back_nth(other.get_span().gap()); },
+                (_, None) => return Err(JsonError::unexpected_eof("value or closing bracket", self.eof_span())),
             };
 
             ast.push(block_value);
@@ -87,22 +110,30 @@ impl<'tk> JsonParser<'tk> {
 
 impl<'tk> JsonParser<'tk> {
     #[inline]
-    pub const fn take_raw(&self, span: Span) -> &[u8] {
+    pub const fn take_raw(&self, span: Span) -> &'tk [u8] {
         self.iter.take_raw(span)
     }
 
     #[inline]
-    pub fn take_slice(&self, span: Span) -> Result<&str, JsonError> {
+    pub fn take_slice(&self, span: Span) -> Result<&'tk str, JsonError> {
         self.iter.take_slice(span)
     }
 
-    // fetching next token, skip all 'whitespace'
+    // empty span at the current position, where an unexpected end of input is reported
+    #[inline]
+    pub(crate) fn eof_span(&mut self) -> Span {
+        let at = self.iter.pos;
+        let (row, col) = self.iter.locate(at);
+        Span::new(at, at).with_location(row, col)
+    }
+
+    // fetching next token, skip all 'whitespace' and comments (only lexed in JSON5)
     #[inline]
     pub fn next_token(&mut self) -> Option<JsonToken> {
         loop {
             let token = self.iter.next()?;
             match token {
-                JsonToken::Punct(Punct::WhiteSpace, _) => continue,
+                JsonToken::Punct(Punct::WhiteSpace, _) | JsonToken::Comment(_) => continue,
                 _ => break Some(token),
             };
         }
@@ -124,9 +155,9 @@ impl<'tk> JsonParser<'tk> {
 #[derive(Debug)]
 pub(crate) enum State {
     // the `usize` is the position in ast
-    // the `HashMap` is indexes of their children
-    // everytime a new property parsed successfully, `HashMap` will inserted a new key-value
-    Object(usize, HashMap<usize, usize>),
+    // the `PropIndexes` are indexes of their children
+    // everytime a new property parsed successfully, its key and position will be inserted
+    Object(usize, PropIndexes),
     // the first `usize` is the position in ast,
     // the second `Vec<usize>` are position of each item
     // everytime a new item parsed successfully, second `usize` will increased by one
@@ -140,6 +171,8 @@ pub(crate) enum State {
 pub(crate) struct JsonCursor {
     level: usize,
     roots: VecDeque<State>,
+    // the latest comma which is not followed by any item yet
+    comma: Option<Span>,
 }
 
 impl JsonCursor {
@@ -147,6 +180,16 @@ impl JsonCursor {
         Self {
             level: 0,
             roots: VecDeque::from_iter([state]),
+            comma: None,
+        }
+    }
+
+    /// a comma followed by a closing bracket is only legit in JSON5
+    #[inline]
+    fn check_trailing_comma(&mut self, parser: &JsonParser<'_>) -> crate::Result<()> {
+        match self.comma.take() {
+            Some(span) if parser.options.dialect.is_strict() => Err(JsonError::new(ErrorKind::NotAllowed(msg::STRICT_TRAILING_COMMA), span)),
+            _ => Ok(()),
         }
     }
 
@@ -161,16 +204,14 @@ impl JsonCursor {
     /// note: PARENT should be an object
     #[inline]
     #[reflection]
-    fn update_prop_index(&mut self, key: Span, parser: &JsonParser<'_>, block_pos: usize) -> crate::Result<()> {
+    fn update_prop_index(&mut self, key: Span, decoded: Option<Box<str>>, parser: &JsonParser<'_>, block_pos: usize) -> crate::Result<()> {
         let Some(State::Object(anchor, ref mut prop_indexes)) = self.roots.back_mut() else {
             return Err(JsonError::custom(format!("[{__fn_ident}] {}", msg::SOON_EOS), Span::default()));
         };
         // insert new item to object indexes
-        let key_slice = parser.take_slice(key)?;
-        let key_hashed = common::hash_str(key_slice) as usize;
         // we should use relative instead absolute position here
         // because lately when we index value, the origin size of ast is hard to trace
-        prop_indexes.insert(key_hashed, block_pos - *anchor);
+        prop_indexes.insert(parser, key, decoded, block_pos - *anchor)?;
 
         Ok(())
     }
@@ -193,10 +234,10 @@ impl JsonCursor {
     fn create_object_block(&mut self, position: usize, span: Span) -> JsonBlock {
         let block = JsonBlock {
             level: self.level,
-            value: JsonValue::Object(HashMap::with_capacity(10), span),
+            value: JsonValue::Object(PropIndexes::default(), span),
         };
         self.level += 1;
-        self.roots.push_back(State::Object(position, HashMap::with_capacity(10)));
+        self.roots.push_back(State::Object(position, PropIndexes::with_capacity(10)));
         block
     }
 
@@ -212,15 +253,12 @@ impl JsonCursor {
     }
 
     #[reflection]
-    fn create_prop_block(&mut self, key: Span, value: JsonType, value_span: Span, parser: &JsonParser<'_>, block_pos: usize) -> crate::Result<JsonBlock> {
+    fn create_prop_block(&mut self, key: Span, decoded: Option<Box<str>>, value: JsonType, value_span: Span, parser: &JsonParser<'_>, block_pos: usize) -> crate::Result<JsonBlock> {
         let Some(State::Object(anchor, ref mut prop_indexes)) = self.roots.back_mut() else {
             return Err(JsonError::custom(format!("[{__fn_ident}] {}", msg::SOON_EOS), Span::default()));
         };
         // insert new item to object indexes
-        let key_slice = parser.take_slice(key.clone())?;
-        let key_hashed = common::hash_str(key_slice) as usize;
-
-        prop_indexes.insert(key_hashed, block_pos - *anchor);
+        prop_indexes.insert(parser, key.clone(), decoded, block_pos - *anchor)?;
 
         Ok(JsonBlock {
             level: self.level,
@@ -274,7 +312,7 @@ impl JsonCursor {
             // if block's type is an Object, then process update its indexes
             State::Object(pos, indexes) => match ast.get_mut(pos) {
                 Some(block) => if let JsonValue::Object(prop_indexes, obj_span) = &mut block.value {
-                    prop_indexes.extend(indexes);
+                    *prop_indexes = indexes;
                     obj_span.end = end;
                 },
                 _ => return Err(JsonError::custom(format!("[{__fn_ident}] the JsonBlock at index {pos} is not an Object, cannot update indexes"), Span::default())),
@@ -291,61 +329,77 @@ impl JsonCursor {
 impl JsonCursor {
     pub fn init(parser: &mut JsonParser<'_>) -> crate::Result<Self> {
         let Some(token) = parser.next_token() else {
-            return Err(JsonError::custom("Reach the end of token stream, soon EOF", Span::default()));
+            return Err(JsonError::empty_json(parser.eof_span()));
         };
 
         match token {
-            JsonToken::Punct(Punct::OpenCurly, _) => Ok(Self::new(State::Object(0, HashMap::new()))),
+            JsonToken::Punct(Punct::OpenCurly, _) => Ok(Self::new(State::Object(0, PropIndexes::default()))),
             JsonToken::Punct(Punct::OpenSquare, _) => Ok(Self::new(State::Array(0, Vec::new()))),
             JsonToken::Data(ty, span) => Ok(Self::new(State::Value(ty, span))),
-            other_type => Err(JsonError::custom("Invalid JSON, should be comment, value, open-curly, open-square", other_type.get_span())),
+            JsonToken::Error(kind, span) => Err(JsonError::new(kind, span)),
+            other_type => Err(JsonError::unexpected_token("value", other_type.describe(), other_type.get_span())),
         }
     }
 
-    #[reflection]
     pub fn parse_object_prop(&mut self, parser: &mut JsonParser<'_>, ast: &mut [JsonBlock]) -> crate::Result<Option<JsonBlock>> {
-        let key_span = match parser.next_token_skip(|tk| matches!(tk, JsonToken::Punct(Punct::WhiteSpace, _) | JsonToken::Comment(_))) {
-            Some(JsonToken::Data(JsonType::Str(_), span)) => span.collapse(1),
-            Some(JsonToken::Data(JsonType::Ident, span)) => span,
+        // a key having escape sequences is decoded once, to be looked up by its actual text
+        let (key_span, decoded) = match parser.next_token_skip(|tk| matches!(tk, JsonToken::Punct(Punct::WhiteSpace, _) | JsonToken::Comment(_))) {
+            Some(JsonToken::Data(JsonType::Str(tokens), span)) => {
+                let decoded = borrow_str(parser, &tokens).is_none()
+                    .then(|| parse_str(parser, &tokens))
+                    .transpose()?;
+                (span.collapse(1), decoded.map(String::into_boxed_str))
+            },
+            Some(JsonToken::Data(JsonType::Ident, span)) => (span.clone(), unescape_ident(parser.take_slice(span)?)),
+            // JSON5 allows reserved words as key (IdentifierName)
+            Some(JsonToken::Data(JsonType::Null | JsonType::Bool(_), span)) if !parser.options.dialect.is_strict() => (span, None),
+            // but a signed `-Infinity` or `+NaN` is a number, not a name
+            Some(JsonToken::Data(JsonType::Num(NumType::Infinity(_) | NumType::NaN(_)), span))
+                if !parser.options.dialect.is_strict() && parser.take_raw(span.clone()).first().is_some_and(u8::is_ascii_alphabetic) => (span, None),
             // hitting the end of this object
             Some(JsonToken::Punct(Punct::CloseCurly, span)) => {
+                self.check_trailing_comma(parser)?;
                 self.rollup_indexes(ast, span.end)?;
                 return Ok(None);
             }
-            Some(JsonToken::Error(err, span)) => return Err(JsonError::custom(format!("[{__fn_ident}] {}", err), span)),
-            Some(tk) => return Err(JsonError::custom(format!("[{__fn_ident}] expect JSON's key is a str/ident, found other"), tk.get_span())),
-            None => return Err(JsonError::custom(format!("[{__fn_ident}] `key` should not be None"), Span::default()))
+            Some(JsonToken::Error(kind, span)) => return Err(JsonError::new(kind, span)),
+            Some(tk) => return Err(JsonError::unexpected_token("key or '}'", tk.describe(), tk.get_span())),
+            None => return Err(JsonError::unexpected_eof("key or '}'", parser.eof_span())),
         };
+        self.comma = None;
 
         let _colon = match parser.next_token() {
             Some(JsonToken::Punct(Punct::Colon, cspan)) => cspan,
-            Some(JsonToken::Error(err, span)) => return Err(JsonError::custom(format!("[{__fn_ident}] {}", err), span)),
-            Some(tk) => return Err(JsonError::custom(format!("[{__fn_ident}] expect next token is a colon, found other"), tk.get_span())),
-            None => return Err(JsonError::custom(format!("[{__fn_ident}] `colon` should not be None"), Span::default()))
+            Some(JsonToken::Error(kind, span)) => return Err(JsonError::new(kind, span)),
+            Some(tk) => return Err(JsonError::unexpected_token("':'", tk.describe(), tk.get_span())),
+            None => return Err(JsonError::unexpected_eof("':'", parser.eof_span())),
         };
 
-        let value = match parser.next_token_skip(|tk| matches!(tk, JsonToken::Punct(Punct::WhiteSpace | Punct::Plus | Punct::Minus, _))) {
+        let value = match parser.next_token() {
             Some(JsonToken::Punct(Punct::OpenCurly, span)) => {
-                self.update_prop_index(key_span, parser, ast.len())?;
+                self.update_prop_index(key_span, decoded, parser, ast.len())?;
                 self.create_object_block(ast.len(), span)
             },
             Some(JsonToken::Punct(Punct::OpenSquare, span)) => {
-                self.update_prop_index(key_span, parser, ast.len())?;
+                self.update_prop_index(key_span, decoded, parser, ast.len())?;
                 self.create_array_block(ast.len(), span)
             },
-            Some(JsonToken::Data(data @ JsonType::Str(_), data_span)) => self.create_prop_block(key_span, data, data_span.collapse(1), parser, ast.len())?,
-            Some(JsonToken::Data(data, data_span)) => self.create_prop_block(key_span, data, data_span, parser, ast.len())?,
-            Some(JsonToken::Error(err, span)) => return Err(JsonError::custom(format!("[{__fn_ident}] {}", err), span)),
-            Some(tk) => return Err(JsonError::custom(format!("[{__fn_ident}] expect next token is primitive value, open-curly or open-square, found other"), tk.get_span())),
-            None => return Err(JsonError::custom(format!("[{__fn_ident}] parsing prop value but reaching None"), Span::default())),
+            Some(JsonToken::Data(data @ JsonType::Str(_), data_span)) => self.create_prop_block(key_span, decoded, data, data_span.collapse(1), parser, ast.len())?,
+            Some(JsonToken::Data(data, data_span)) => self.create_prop_block(key_span, decoded, data, data_span, parser, ast.len())?,
+            Some(JsonToken::Error(kind, span)) => return Err(JsonError::new(kind, span)),
+            Some(tk) => return Err(JsonError::unexpected_token("value", tk.describe(), tk.get_span())),
+            None => return Err(JsonError::unexpected_eof("value", parser.eof_span())),
         };
 
         Ok(Some(value))
     }
 
-    #[reflection]
     pub fn parse_array_item(&mut self, parser: &mut JsonParser<'_>, ast: &mut [JsonBlock]) -> crate::Result<Option<JsonBlock>> {
-        let item_value = match parser.next_token_skip(|tk| matches!(tk, JsonToken::Punct(Punct::WhiteSpace | Punct::Plus | Punct::Minus, _) | JsonToken::Comment(_))) {
+        let next_item = parser.next_token_skip(|tk| matches!(tk, JsonToken::Punct(Punct::WhiteSpace, _) | JsonToken::Comment(_)));
+        if !matches!(next_item, Some(JsonToken::Punct(Punct::CloseSquare, _))) {
+            self.comma = None;
+        }
+        let item_value = match next_item {
             Some(JsonToken::Data(data, data_span)) => self.create_item_block(ast.len(), data, data_span)?,
             Some(JsonToken::Punct(Punct::OpenCurly, span)) => {
                 self.update_array_length(ast.len())?;
@@ -357,25 +411,27 @@ impl JsonCursor {
             },
             // hitting the end of this array
             Some(JsonToken::Punct(Punct::CloseSquare, span)) =>  {
+                self.check_trailing_comma(parser)?;
                 self.rollup_indexes(ast, span.end)?;
                 return Ok(None)
             },
-            Some(JsonToken::Punct(_, span)) => return Err(JsonError::invalid_array(span)),
-            Some(JsonToken::Error(err, span)) => return Err(JsonError::custom(format!("[{__fn_ident}] {}", err), span)),
-            Some(JsonToken::Comment(span)) => return Err(JsonError::custom(format!("[{__fn_ident}] should not reaching this state, because all comments must be stripped all"), span)),
-            None => return Err(JsonError::custom(format!("[{__fn_ident}] reaching None when parsing"), Span::default()))
+            Some(JsonToken::Error(kind, span)) => return Err(JsonError::new(kind, span)),
+            // comments were already stripped
+            Some(tk) => return Err(JsonError::unexpected_token("value or ']'", tk.describe(), tk.get_span())),
+            None => return Err(JsonError::unexpected_eof("value or ']'", parser.eof_span())),
         };
 
         Ok(Some(item_value))
     }
 
     // the whole source is single-value
-    #[reflection]
     pub fn parse_value(&mut self, parser: &mut JsonParser<'_>) -> crate::Result<Option<JsonBlock>> {
-        let next_item = parser.next_token_skip(|tk| matches!(tk, JsonToken::Punct(Punct::WhiteSpace | Punct::Plus | Punct::Minus, _) | JsonToken::Comment(_)));
+        let next_item = parser.next_token_skip(|tk| matches!(tk, JsonToken::Punct(Punct::WhiteSpace, _) | JsonToken::Comment(_)));
         let item_value = match next_item {
             Some(JsonToken::Data(data, data_span)) => self.create_value_block(data, data_span),
-            _ => return Err(JsonError::custom(format!("[{__fn_ident}] invalid json value"), Span::default())),
+            Some(JsonToken::Error(kind, span)) => return Err(JsonError::new(kind, span)),
+            Some(tk) => return Err(JsonError::unexpected_token("value", tk.describe(), tk.get_span())),
+            None => return Err(JsonError::unexpected_eof("value", parser.eof_span())),
         };
         Ok(Some(item_value))
     }
@@ -402,4 +458,20 @@ mod tests {
 
         assert!(out.inspect_err(|err| eprintln!("{err}")).is_ok());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn parse_comment_around_colon() {
+        use crate::indexer::JsonIdx;
+
+        let mut parser = JsonParser::new("/* a */ { a /* b */ : /* c */ 1, b // d\n : // e\n [ /* f */ 2 /* g */ ] /* h */ } // i");
+        let out = parser.parse().unwrap();
+
+        assert_eq!(Ok("1"), out.index("a").unwrap().to_slice());
+        assert_eq!(Ok("2"), out.index("b").unwrap().index(0).unwrap().to_slice());
+
+        let mut strict = JsonParser::with_options("{ \"a\" /* b */ : 1 }", ParserOptions::strict());
+        assert_eq!(&ErrorKind::NotAllowed("comment"), strict.parse().unwrap_err().kind());
+        let mut strict = JsonParser::with_options("{ \"a\": /* c */ 1 }", ParserOptions::strict());
+        assert_eq!(&ErrorKind::NotAllowed("comment"), strict.parse().unwrap_err().kind());
+    }
+}
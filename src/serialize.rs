@@ -0,0 +1,712 @@
+use std::{collections::{BTreeMap, HashMap}, fmt::{self, Display, Write}, io};
+
+use crate::{lexer::{is_ident_part, is_ident_start}, option::Dialect};
+
+pub trait Serialize {
+    fn serialize<W: Write>(&self, writer: &mut JsonWriter<W>) -> fmt::Result;
+
+    /// write the properties of this value into an object opened by the caller, used by `#[jsode(flatten)]`.
+    /// Only maps and derived structs with named fields are made of properties, anything else fails
+    fn serialize_fields<W: Write>(&self, _writer: &mut JsonWriter<W>) -> fmt::Result {
+        Err(fmt::Error)
+    }
+
+    /// shortcut for serializing into a compact JSON string
+    fn to_json(&self) -> Result<String, fmt::Error> {
+        self.to_json_with(&WriterOptions::new())
+    }
+
+    /// fail when the value cannot be written, such as a `#[jsode(flatten)]` field which is not made of properties,
+    /// the partially written string is never returned
+    fn to_json_with(&self, options: &WriterOptions) -> Result<String, fmt::Error> {
+        let mut writer = JsonWriter::with_options(String::new(), options.clone());
+        self.serialize(&mut writer)?;
+        Ok(writer.into_inner())
+    }
+
+    /// serialize into any `std::io::Write`, such as a file or a socket
+    fn write_json<O: io::Write>(&self, out: O, options: &WriterOptions) -> io::Result<()> {
+        let mut writer = JsonWriter::with_options(IoWriter::new(out), options.clone());
+        match self.serialize(&mut writer) {
+            Ok(_) => Ok(()),
+            Err(_) => Err(writer.into_inner().error.unwrap_or_else(|| io::Error::other("failed to serialize value"))),
+        }
+    }
+}
+
+/// Quote surrounding strings (and keys) in [`Dialect::Json5`] output,
+/// strict JSON output is always double-quoted.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Default)]
+pub enum Quote {
+    #[default]
+    Double,
+    Single,
+}
+
+impl Quote {
+    #[inline(always)]
+    pub const fn as_char(&self) -> char {
+        match self {
+            Self::Double => '"',
+            Self::Single => '\'',
+        }
+    }
+}
+
+/// Options controlling how [`JsonWriter`] lays out its output.
+///
+/// ```
+/// use jsode::prelude::*;
+///
+/// let pretty = vec![1, 2].to_json_with(&WriterOptions::pretty()).unwrap();
+/// assert_eq!("[\n  1,\n  2\n]", pretty);
+///
+/// let json5 = WriterOptions::json5().with_quote(Quote::Single).with_trailing_comma(true);
+/// assert_eq!("{ids:[1,2,],}", std::collections::BTreeMap::from([("ids", [1, 2])]).to_json_with(&json5).unwrap());
+/// ```
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct WriterOptions {
+    // number of spaces per level, `None` means compact output
+    pub(crate) indent: Option<usize>,
+    pub(crate) dialect: Dialect,
+    pub(crate) quote: Quote,
+    pub(crate) trailing_comma: bool,
+}
+
+impl Default for WriterOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl WriterOptions {
+    /// compact strict JSON, no whitespace at all
+    #[inline]
+    pub const fn new() -> Self {
+        Self { indent: None, dialect: Dialect::Strict, quote: Quote::Double, trailing_comma: false }
+    }
+
+    /// shortcut for `WriterOptions::new().with_dialect(Dialect::Json5)`
+    #[inline]
+    pub const fn json5() -> Self {
+        Self::new().with_dialect(Dialect::Json5)
+    }
+
+    /// shortcut for `WriterOptions::new().with_indent(2)`
+    #[inline]
+    pub const fn pretty() -> Self {
+        Self::new().with_indent(2)
+    }
+
+    #[inline]
+    pub const fn with_indent(mut self, spaces: usize) -> Self {
+        self.indent = Some(spaces);
+        self
+    }
+
+    /// [`Dialect::Json5`] output keys without quotes when they are identifiers, `Infinity`/`NaN`
+    /// for non-finite floats, [`Hex`] as hexadecimal and keep comments
+    #[inline]
+    pub const fn with_dialect(mut self, dialect: Dialect) -> Self {
+        self.dialect = dialect;
+        self
+    }
+
+    /// only effective in [`Dialect::Json5`]
+    #[inline]
+    pub const fn with_quote(mut self, quote: Quote) -> Self {
+        self.quote = quote;
+        self
+    }
+
+    /// put a comma after the last member of every non-empty object/array, only effective in [`Dialect::Json5`]
+    #[inline]
+    pub const fn with_trailing_comma(mut self, trailing_comma: bool) -> Self {
+        self.trailing_comma = trailing_comma;
+        self
+    }
+
+    #[inline]
+    pub const fn is_pretty(&self) -> bool {
+        self.indent.is_some()
+    }
+
+    #[inline]
+    pub const fn dialect(&self) -> Dialect {
+        self.dialect
+    }
+
+    #[inline]
+    const fn is_json5(&self) -> bool {
+        !self.dialect.is_strict()
+    }
+
+    #[inline]
+    const fn quote_char(&self) -> char {
+        if self.is_json5() { self.quote.as_char() } else { '"' }
+    }
+}
+
+/// Emit JSON into any `fmt::Write`, object and array are written member by member.
+///
+/// ```
+/// use jsode::prelude::*;
+///
+/// let mut writer = JsonWriter::new(String::new());
+/// writer.begin_object().unwrap();
+/// writer.field("name", "jsode").unwrap();
+/// writer.field("tags", &vec!["json", "json5"]).unwrap();
+/// writer.end_object().unwrap();
+///
+/// assert_eq!(r#"{"name":"jsode","tags":["json","json5"]}"#, writer.into_inner());
+/// ```
+#[derive(Debug)]
+pub struct JsonWriter<W> {
+    out: W,
+    options: WriterOptions,
+    // one entry per opened object/array, `true` until its first member was written
+    first: Vec<bool>,
+    // a comment was just written, the next member is already separated from the previous one
+    commented: bool,
+}
+
+impl <W: Write> JsonWriter<W> {
+    #[inline]
+    pub fn new(out: W) -> Self {
+        Self::with_options(out, WriterOptions::default())
+    }
+
+    #[inline]
+    pub fn with_options(out: W, options: WriterOptions) -> Self {
+        Self { out, options, first: Vec::new(), commented: false }
+    }
+
+    #[inline]
+    pub const fn options(&self) -> &WriterOptions {
+        &self.options
+    }
+
+    #[inline]
+    pub fn into_inner(self) -> W {
+        self.out
+    }
+
+    #[inline]
+    pub fn serialize<T: Serialize + ?Sized>(&mut self, value: &T) -> fmt::Result {
+        value.serialize(self)
+    }
+
+    pub fn begin_object(&mut self) -> fmt::Result {
+        self.begin('{')
+    }
+
+    pub fn end_object(&mut self) -> fmt::Result {
+        self.end('}')
+    }
+
+    pub fn begin_array(&mut self) -> fmt::Result {
+        self.begin('[')
+    }
+
+    pub fn end_array(&mut self) -> fmt::Result {
+        self.end(']')
+    }
+
+    /// write the key of the next property, must be followed by its value
+    pub fn key(&mut self, key: &str) -> fmt::Result {
+        self.separate()?;
+        if self.options.is_json5() && is_identifier(key) {
+            self.out.write_str(key)?;
+        } else {
+            self.write_str(key)?;
+        }
+        self.out.write_char(':')?;
+        if self.options.is_pretty() {
+            self.out.write_char(' ')?;
+        }
+        Ok(())
+    }
+
+    /// write a whole property, `key: value`
+    pub fn field<T: Serialize + ?Sized>(&mut self, key: &str, value: &T) -> fmt::Result {
+        self.key(key)?;
+        value.serialize(self)
+    }
+
+    /// write an array's item
+    pub fn element<T: Serialize + ?Sized>(&mut self, value: &T) -> fmt::Result {
+        self.separate()?;
+        value.serialize(self)
+    }
+
+    /// attach a comment to the next member, written as `// ..` in pretty mode and `/* .. */` in compact mode.
+    /// Comment does not exist in strict JSON, so it is skipped
+    pub fn comment(&mut self, text: &str) -> fmt::Result {
+        if !self.options.is_json5() {
+            return Ok(());
+        }
+        self.separate()?;
+        if self.options.is_pretty() {
+            for (n, line) in text.lines().enumerate() {
+                if n > 0 { self.newline()?; }
+                write!(self.out, "// {line}")?;
+            }
+            self.newline()?;
+        } else {
+            // the comment must not be closed early
+            write!(self.out, "/* {} */", text.replace("*/", "* /"))?;
+        }
+        self.commented = true;
+        Ok(())
+    }
+
+    pub fn write_null(&mut self) -> fmt::Result {
+        self.out.write_str("null")
+    }
+
+    pub fn write_bool(&mut self, value: bool) -> fmt::Result {
+        self.out.write_str(if value { "true" } else { "false" })
+    }
+
+    pub fn write_integer(&mut self, value: impl Display) -> fmt::Result {
+        write!(self.out, "{value}")
+    }
+
+    /// integer as hexadecimal (`0x1F`) in [`Dialect::Json5`], decimal otherwise
+    pub fn write_hex(&mut self, value: impl fmt::UpperHex + Display + PartialOrd + Default) -> fmt::Result {
+        if !self.options.is_json5() {
+            return self.write_integer(value);
+        }
+        if value < Default::default() {
+            // `UpperHex` of a negative number is its two's complement
+            let decimal = value.to_string();
+            let magnitude = decimal.trim_start_matches('-').parse::<u128>().map_err(|_| fmt::Error)?;
+            write!(self.out, "-{magnitude:#X}")
+        } else {
+            write!(self.out, "{value:#X}")
+        }
+    }
+
+    /// non-finite float has no representation in JSON, they are written as `null`
+    /// (`Infinity`, `-Infinity` or `NaN` in [`Dialect::Json5`])
+    pub fn write_float(&mut self, value: f64) -> fmt::Result {
+        if value.is_finite() {
+            // `Debug` keep the fraction of round numbers (`1.0` instead of `1`)
+            write!(self.out, "{value:?}")
+        } else if !self.options.is_json5() {
+            self.write_null()
+        } else if value.is_nan() {
+            self.out.write_str("NaN")
+        } else if value.is_sign_negative() {
+            self.out.write_str("-Infinity")
+        } else {
+            self.out.write_str("Infinity")
+        }
+    }
+
+    pub fn write_str(&mut self, value: &str) -> fmt::Result {
+        let quote = self.options.quote_char();
+        self.out.write_char(quote)?;
+        let mut start = 0;
+        for (pos, c) in value.char_indices() {
+            let escaped = match c {
+                '"' if quote == '"' => "\\\"",
+                '\'' if quote == '\'' => "\\'",
+                '\\' => "\\\\",
+                '\n' => "\\n",
+                '\r' => "\\r",
+                '\t' => "\\t",
+                '\u{08}' => "\\b",
+                '\u{0C}' => "\\f",
+                c if c < ' ' => "",
+                _ => continue,
+            };
+            self.out.write_str(&value[start..pos])?;
+            if escaped.is_empty() {
+                write!(self.out, "\\u{:04x}", c as u32)?;
+            } else {
+                self.out.write_str(escaped)?;
+            }
+            start = pos + c.len_utf8();
+        }
+        self.out.write_str(&value[start..])?;
+        self.out.write_char(quote)
+    }
+
+    fn begin(&mut self, open: char) -> fmt::Result {
+        self.first.push(true);
+        self.out.write_char(open)
+    }
+
+    fn end(&mut self, close: char) -> fmt::Result {
+        // a comment closing the container is already preceded by the last comma, if any
+        let commented = std::mem::take(&mut self.commented);
+        // an empty object/array stay in a single line
+        if self.first.pop() == Some(false) {
+            if self.options.is_json5() && self.options.trailing_comma && !commented {
+                self.out.write_char(',')?;
+            }
+            self.newline()?;
+        }
+        self.out.write_char(close)
+    }
+
+    // comma between members, then break line in pretty mode
+    fn separate(&mut self) -> fmt::Result {
+        if std::mem::take(&mut self.commented) {
+            return Ok(());
+        }
+        if let Some(first) = self.first.last_mut() {
+            if !*first {
+                self.out.write_char(',')?;
+            }
+            *first = false;
+        }
+        self.newline()
+    }
+
+    fn newline(&mut self) -> fmt::Result {
+        let Some(indent) = self.options.indent else {
+            return Ok(());
+        };
+        self.out.write_char('\n')?;
+        for _ in 0..indent * self.first.len() {
+            self.out.write_char(' ')?;
+        }
+        Ok(())
+    }
+}
+
+// keys which can be written without quotes, limited to what `JsonParser` read as an identifier
+fn is_identifier(key: &str) -> bool {
+    let mut chars = key.chars();
+    chars.next().is_some_and(is_ident_start) && chars.all(is_ident_part)
+}
+
+/// Integer written as hexadecimal in [`Dialect::Json5`] output.
+///
+/// ```
+/// use jsode::prelude::*;
+///
+/// assert_eq!("0xC0FFEE", Hex(0xC0FFEE).to_json_with(&WriterOptions::json5()).unwrap());
+/// assert_eq!("12648430", Hex(0xC0FFEE).to_json().unwrap());
+/// ```
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug, Default, Hash)]
+pub struct Hex<T>(pub T);
+
+/// Adapter writing into a `std::io::Write`, the latest I/O error is kept
+/// because `fmt::Error` cannot carry it.
+#[derive(Debug)]
+pub struct IoWriter<O> {
+    inner: O,
+    error: Option<io::Error>,
+}
+
+impl <O: io::Write> IoWriter<O> {
+    pub const fn new(inner: O) -> Self {
+        Self { inner, error: None }
+    }
+
+    pub fn into_inner(self) -> O {
+        self.inner
+    }
+
+    pub fn take_error(&mut self) -> Option<io::Error> {
+        self.error.take()
+    }
+}
+
+impl <O: io::Write> Write for IoWriter<O> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.inner.write_all(s.as_bytes()).map_err(|err| {
+            self.error = Some(err);
+            fmt::Error
+        })
+    }
+}
+
+macro_rules! impl_integer_serialization {
+    ($($type:ty),+) => {$(
+        impl Serialize for $type {
+            fn serialize<W: Write>(&self, writer: &mut JsonWriter<W>) -> fmt::Result {
+                writer.write_integer(self)
+            }
+        }
+    )+};
+}
+
+impl_integer_serialization!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl <T: fmt::UpperHex + Display + PartialOrd + Default + Copy> Serialize for Hex<T> {
+    fn serialize<W: Write>(&self, writer: &mut JsonWriter<W>) -> fmt::Result {
+        writer.write_hex(self.0)
+    }
+}
+
+impl Serialize for f32 {
+    fn serialize<W: Write>(&self, writer: &mut JsonWriter<W>) -> fmt::Result {
+        if self.is_finite() {
+            // widening into f64 would print the binary error, `0.1` as `0.10000000149011612`
+            write!(writer.out, "{self:?}")
+        } else {
+            writer.write_float(f64::from(*self))
+        }
+    }
+}
+
+impl Serialize for f64 {
+    fn serialize<W: Write>(&self, writer: &mut JsonWriter<W>) -> fmt::Result {
+        writer.write_float(*self)
+    }
+}
+
+impl Serialize for bool {
+    fn serialize<W: Write>(&self, writer: &mut JsonWriter<W>) -> fmt::Result {
+        writer.write_bool(*self)
+    }
+}
+
+// the counterpart of `Deserialize for ()`
+impl Serialize for () {
+    fn serialize<W: Write>(&self, writer: &mut JsonWriter<W>) -> fmt::Result {
+        writer.write_null()
+    }
+}
+
+impl Serialize for char {
+    fn serialize<W: Write>(&self, writer: &mut JsonWriter<W>) -> fmt::Result {
+        writer.write_str(self.encode_utf8(&mut [0; 4]))
+    }
+}
+
+impl Serialize for str {
+    fn serialize<W: Write>(&self, writer: &mut JsonWriter<W>) -> fmt::Result {
+        writer.write_str(self)
+    }
+}
+
+impl Serialize for String {
+    fn serialize<W: Write>(&self, writer: &mut JsonWriter<W>) -> fmt::Result {
+        writer.write_str(self)
+    }
+}
+
+impl <T: Serialize + ?Sized> Serialize for &T {
+    fn serialize<W: Write>(&self, writer: &mut JsonWriter<W>) -> fmt::Result {
+        (**self).serialize(writer)
+    }
+
+    fn serialize_fields<W: Write>(&self, writer: &mut JsonWriter<W>) -> fmt::Result {
+        (**self).serialize_fields(writer)
+    }
+}
+
+impl <T: Serialize + ?Sized> Serialize for Box<T> {
+    fn serialize<W: Write>(&self, writer: &mut JsonWriter<W>) -> fmt::Result {
+        (**self).serialize(writer)
+    }
+
+    fn serialize_fields<W: Write>(&self, writer: &mut JsonWriter<W>) -> fmt::Result {
+        (**self).serialize_fields(writer)
+    }
+}
+
+impl <T: Serialize> Serialize for Option<T> {
+    fn serialize<W: Write>(&self, writer: &mut JsonWriter<W>) -> fmt::Result {
+        match self {
+            Some(value) => value.serialize(writer),
+            None => writer.write_null(),
+        }
+    }
+}
+
+impl <T: Serialize> Serialize for [T] {
+    fn serialize<W: Write>(&self, writer: &mut JsonWriter<W>) -> fmt::Result {
+        writer.begin_array()?;
+        for item in self {
+            writer.element(item)?;
+        }
+        writer.end_array()
+    }
+}
+
+impl <T: Serialize, const N: usize> Serialize for [T; N] {
+    fn serialize<W: Write>(&self, writer: &mut JsonWriter<W>) -> fmt::Result {
+        self.as_slice().serialize(writer)
+    }
+}
+
+impl <T: Serialize> Serialize for Vec<T> {
+    fn serialize<W: Write>(&self, writer: &mut JsonWriter<W>) -> fmt::Result {
+        self.as_slice().serialize(writer)
+    }
+}
+
+impl <K: Display, V: Serialize, S> Serialize for HashMap<K, V, S> {
+    fn serialize<W: Write>(&self, writer: &mut JsonWriter<W>) -> fmt::Result {
+        writer.begin_object()?;
+        serialize_entries(self.iter(), writer)?;
+        writer.end_object()
+    }
+
+    fn serialize_fields<W: Write>(&self, writer: &mut JsonWriter<W>) -> fmt::Result {
+        serialize_entries(self.iter(), writer)
+    }
+}
+
+impl <K: Display, V: Serialize> Serialize for BTreeMap<K, V> {
+    fn serialize<W: Write>(&self, writer: &mut JsonWriter<W>) -> fmt::Result {
+        writer.begin_object()?;
+        serialize_entries(self.iter(), writer)?;
+        writer.end_object()
+    }
+
+    fn serialize_fields<W: Write>(&self, writer: &mut JsonWriter<W>) -> fmt::Result {
+        serialize_entries(self.iter(), writer)
+    }
+}
+
+// JSON's key is always a string, non-string keys are written by their `Display`
+fn serialize_entries<'a, K: Display + 'a, V: Serialize + 'a, W: Write>(
+    entries: impl Iterator<Item = (&'a K, &'a V)>,
+    writer: &mut JsonWriter<W>,
+) -> fmt::Result {
+    let mut key_buf = String::new();
+    for (key, value) in entries {
+        key_buf.clear();
+        write!(key_buf, "{key}")?;
+        writer.field(&key_buf, value)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::{deserialize::JsonPsr, parser::JsonParser};
+
+    use super::*;
+
+    #[test]
+    fn write_compact() {
+        let mut map = BTreeMap::new();
+        map.insert("b", vec![Some(1.5), None]);
+        map.insert("a", vec![]);
+
+        assert_eq!(r#"{"a":[],"b":[1.5,null]}"#, map.to_json().unwrap());
+        assert_eq!("[1.0,0.1,null]", [1.0, 0.1, f32::NAN].to_json().unwrap());
+        assert_eq!("-128", (-128i8).to_json().unwrap());
+        assert_eq!("\"x\"", 'x'.to_json().unwrap());
+    }
+
+    #[test]
+    fn write_pretty() {
+        let mut map = BTreeMap::new();
+        map.insert(1, vec![1, 2]);
+        map.insert(2, vec![]);
+
+        assert_eq!("{\n  \"1\": [\n    1,\n    2\n  ],\n  \"2\": []\n}", map.to_json_with(&WriterOptions::pretty()).unwrap());
+        assert_eq!("{\n    \"1\": [\n        1,\n        2\n    ],\n    \"2\": []\n}", map.to_json_with(&WriterOptions::new().with_indent(4)).unwrap());
+    }
+
+    #[test]
+    fn escape_string() {
+        let src = "quote \" backslash \\ tab \t nul \u{0} é 😀";
+
+        assert_eq!(r#""quote \" backslash \\ tab \t nul \u0000 é 😀""#, src.to_json().unwrap());
+        assert_eq!(Ok(src.to_string()), JsonParser::new(&src.to_json().unwrap()).parse().unwrap().parse_into::<String>());
+    }
+
+    #[test]
+    fn write_json5() {
+        let options = WriterOptions::json5();
+        let map = BTreeMap::from([("name", "it's"), ("first-name", "a\"b"), ("_1", "")]);
+
+        assert_eq!(r#"{_1:"","first-name":"a\"b",name:"it's"}"#, map.to_json_with(&options).unwrap());
+        assert_eq!(r#"{_1:'','first-name':'a"b',name:'it\'s'}"#, map.to_json_with(&options.clone().with_quote(Quote::Single)).unwrap());
+        // single quote is not JSON
+        assert_eq!(r#"{"_1":"","first-name":"a\"b","name":"it's"}"#, map.to_json_with(&WriterOptions::new().with_quote(Quote::Single)).unwrap());
+    }
+
+    #[test]
+    fn write_json5_number() {
+        let options = WriterOptions::json5();
+
+        assert_eq!("[Infinity,-Infinity,NaN]", [f64::INFINITY, f64::NEG_INFINITY, f64::NAN].to_json_with(&options).unwrap());
+        assert_eq!("[null,null,null]", [f64::INFINITY, f64::NEG_INFINITY, f64::NAN].to_json().unwrap());
+        assert_eq!("[0x1F,-0x80,0x0]", [Hex(31i64), Hex(-128), Hex(0)].to_json_with(&options).unwrap());
+        assert_eq!("-0x80", Hex(i8::MIN).to_json_with(&options).unwrap());
+    }
+
+    #[test]
+    fn write_trailing_comma_and_comment() {
+        let options = WriterOptions::json5().with_trailing_comma(true);
+        let mut writer = JsonWriter::with_options(String::new(), options.clone().with_indent(2));
+        writer.begin_object().unwrap();
+        writer.comment("listen port\nmust be > 1024").unwrap();
+        writer.field("port", &8080).unwrap();
+        writer.field("empty", &Vec::<u8>::new()).unwrap();
+        writer.end_object().unwrap();
+
+        assert_eq!("{\n  // listen port\n  // must be > 1024\n  port: 8080,\n  empty: [],\n}", writer.into_inner());
+
+        let mut writer = JsonWriter::with_options(String::new(), options);
+        writer.begin_array().unwrap();
+        writer.comment("*/ closed").unwrap();
+        writer.element(&1).unwrap();
+        writer.end_array().unwrap();
+
+        assert_eq!("[/* * / closed */1,]", writer.into_inner());
+    }
+
+    #[test]
+    fn write_comment_closing_container() {
+        // array whose last member is a comment
+        struct Commented(Vec<u8>);
+        impl Serialize for Commented {
+            fn serialize<W: Write>(&self, writer: &mut JsonWriter<W>) -> fmt::Result {
+                writer.begin_array()?;
+                for item in &self.0 {
+                    writer.element(item)?;
+                }
+                writer.comment("c")?;
+                writer.end_array()
+            }
+        }
+
+        let write = |options: WriterOptions, items: &[Commented]| {
+            let mut writer = JsonWriter::with_options(String::new(), options);
+            writer.begin_array().unwrap();
+            for item in items {
+                writer.element(item).unwrap();
+            }
+            writer.element(&2).unwrap();
+            writer.end_array().unwrap();
+            writer.into_inner()
+        };
+
+        assert_eq!("[[1,/* c */],2]", write(WriterOptions::json5(), &[Commented(vec![1])]));
+        assert_eq!("[[/* c */],[1,/* c */],2,]", write(WriterOptions::json5().with_trailing_comma(true), &[Commented(vec![]), Commented(vec![1])]));
+    }
+
+    #[test]
+    fn skip_comment_in_json() {
+        let mut writer = JsonWriter::new(String::new());
+        writer.begin_array().unwrap();
+        writer.element(&1).unwrap();
+        writer.comment("dropped").unwrap();
+        writer.element(&2).unwrap();
+        writer.end_array().unwrap();
+
+        assert_eq!("[1,2]", writer.into_inner());
+    }
+
+    #[test]
+    fn write_into_io() {
+        let mut out = Vec::<u8>::new();
+        vec!["a", "b"].write_json(&mut out, &WriterOptions::new()).unwrap();
+
+        assert_eq!(br#"["a","b"]"#, out.as_slice());
+    }
+}
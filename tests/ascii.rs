@@ -3,7 +3,7 @@ fn escape_character() {
 
     println!("{:?}", "\\ U+00FF".as_bytes());
     println!("{}", b'\"');
-    println!("{}",  std::str::from_utf8(&[b'\\']).unwrap());
+    println!("{}",  std::str::from_utf8(b"\\").unwrap());
 
     println!("\u{62}");
 
@@ -0,0 +1,6 @@
+use jsode::prelude::*;
+
+// parse the whole source then deserialize it, shared by the integration tests
+pub fn parse<T: for<'src> Deserialize<'src>>(src: &str) -> Result<T> {
+    JsonParser::new(src).parse()?.parse_into::<T>()
+}
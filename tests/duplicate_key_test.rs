@@ -0,0 +1,63 @@
+use jsode::prelude::*;
+
+const SRC: &str = "{ a: 1, b: 2, a: { c: 3 }, a: 4 }";
+
+fn parser(duplicate_key: DuplicateKey) -> JsonParser<'static> {
+    JsonParser::with_options(SRC, ParserOptions::new().with_duplicate_key(duplicate_key))
+}
+
+#[test]
+fn keep_last_by_default() -> Result<()> {
+    let mut src = JsonParser::new(SRC);
+    let ast = src.parse()?;
+
+    assert_eq!(Ok("4"), ast.index("a").unwrap().to_slice());
+    assert_eq!(vec!["a", "b"], ast.keys().collect::<Vec<_>>());
+    Ok(())
+}
+
+#[test]
+fn keep_first() -> Result<()> {
+    let mut src = parser(DuplicateKey::First);
+    let ast = src.parse()?;
+
+    assert_eq!(Ok("1"), ast.index("a").unwrap().to_slice());
+    assert_eq!(vec!["a", "b"], ast.keys().collect::<Vec<_>>());
+    assert_eq!(1, ast.get_all("a").count());
+    Ok(())
+}
+
+#[test]
+fn keep_all() -> Result<()> {
+    let mut src = parser(DuplicateKey::All);
+    let ast = src.parse()?;
+
+    let values = ast.get_all("a").map(|it| it.to_slice().map(str::to_string)).collect::<Result<Vec<_>>>()?;
+    assert_eq!(vec!["1", "{ c: 3 }", "4"], values);
+    assert_eq!(Ok("4"), ast.index("a").unwrap().to_slice());
+    assert_eq!(vec!["a", "b", "a", "a"], ast.keys().collect::<Vec<_>>());
+    assert_eq!(0, ast.get_all("z").count());
+    Ok(())
+}
+
+#[test]
+fn reject_duplicate() {
+    let err = parser(DuplicateKey::Error).parse().unwrap_err();
+
+    let ErrorKind::DuplicateKey { key, first } = err.kind() else {
+        panic!("unexpected error {err:?}");
+    };
+    assert_eq!("a", key);
+    assert_eq!(&Span::new(2, 3), first);
+    assert_eq!(&Span::new(14, 15), err.span());
+    assert_eq!("already exist key: `a`, first defined at line 1, column 3", err.to_string());
+}
+
+#[test]
+fn reject_duplicate_in_nested_object() {
+    let options = ParserOptions::new().with_duplicate_key(DuplicateKey::Error);
+    let err = JsonParser::with_options("[{ \"x\": 1 }, { \"x\": 1, \"x\": 2 }]", options.clone()).parse().unwrap_err();
+
+    assert!(matches!(err.kind(), ErrorKind::DuplicateKey { key, .. } if key == "x"));
+    assert!(JsonParser::with_options("[{ \"x\": 1 }, { \"x\": 2 }]", options).parse().is_ok());
+}
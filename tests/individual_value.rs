@@ -40,4 +40,37 @@ fn invidiual_vec() {
     let mut parser = JsonParser::new("[1,2,3,4]");
     let out = parser.parse().unwrap().parse_into::<Vec<u8>>();
     assert_eq!(Ok(vec![1,2,3,4]), out);
-}
\ No newline at end of file
+}
+
+#[test]
+fn invidiual_escaped_str() {
+    let cases = [
+        (r#""caf\u00e9""#, "café"),
+        (r#""\u0048\u0069""#, "Hi"),
+        (r"'\x41\xe9'", "Aé"),
+        (r#""\ud83d\ude00!""#, "😀!"),
+        (r#""\uD83D\uDE00""#, "😀"),
+        (r#""a\tb\/""#, "a\tb/"),
+    ];
+
+    for (src, expected) in cases {
+        let out = JsonParser::new(src).parse().unwrap().parse_into::<String>();
+        assert_eq!(Ok(expected.to_string()), out, "{src}");
+    }
+}
+
+#[test]
+fn invidiual_unpaired_surrogate() {
+    let cases = [
+        (r#""\ud83d""#, r"\ud83d", 1..7),
+        (r#""ab\ude00""#, r"\ude00", 3..9),
+        (r#""\ud83dA""#, r"\ud83d", 1..7),
+        (r#""\ud83dx""#, r"\ud83d", 1..7),
+    ];
+
+    for (src, escape, range) in cases {
+        let err = JsonParser::new(src).parse().unwrap().parse_into::<String>().unwrap_err();
+        assert_eq!(&ErrorKind::UnpairedSurrogate(escape.into()), err.kind(), "{src}");
+        assert_eq!((range.start, range.end), (err.span().start, err.span().end), "{src}");
+    }
+}
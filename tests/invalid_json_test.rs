@@ -29,4 +29,39 @@ should_fail_test!(n_array_newlines_unclosed);
 should_fail_test!(n_array_unclosed_trailing_comma);
 should_fail_test!(n_structure_close_unopened_array);
 should_fail_test!(n_string_with_trailing_garbage);
-should_fail_test!(n_string_single_string_no_double_quotes);
\ No newline at end of file
+should_fail_test!(n_string_single_string_no_double_quotes);
+
+#[test]
+fn error_location() {
+    let mut json = JsonParser::new("{\n  a: 1,\n  b: 'oops\n}");
+    let err = json.parse().unwrap_err();
+
+    assert_eq!((3, 6), (err.span().row, err.span().col));
+    assert!(format!("{err:?}").starts_with("source file panic at line 3, column 6"));
+}
+
+#[test]
+fn error_kind() {
+    let cases = [
+        ("", ErrorKind::EmptyJson),
+        ("[1, 2", ErrorKind::UnexpectedEof { expected: "',' or closing bracket" }),
+        ("[1 2]", ErrorKind::UnexpectedToken { expected: "',' or closing bracket", found: "integer".into() }),
+        ("{ a 1 }", ErrorKind::UnexpectedToken { expected: "':'", found: "integer".into() }),
+        ("{ a: 1 } 2", ErrorKind::TrailingData),
+        ("['abc]", ErrorKind::UnterminatedString('\'')),
+        ("['\\u12G4']", ErrorKind::InvalidEscape("\\u12G".into())),
+        ("[1, €]", ErrorKind::UnsupportedChar('€')),
+    ];
+
+    for (src, expected) in cases {
+        let err = JsonParser::new(src).parse().expect_err(src);
+        assert_eq!(&expected, err.kind(), "{src}");
+    }
+}
+
+#[test]
+fn error_is_std_error() {
+    let err: Box<dyn std::error::Error> = Box::new(JsonParser::new("[1,").parse().unwrap_err());
+
+    assert_eq!("unexpected end of input, expect value or ']'", err.to_string());
+}
@@ -0,0 +1,143 @@
+use jsode::prelude::*;
+
+// conformance corpus of JSON5 numbers, built from the examples of https://spec.json5.org/#numbers
+// and the `numbers` cases of https://github.com/json5/json5-tests
+
+fn parse_number(src: &str) -> Result<f64> {
+    JsonParser::new(src).parse()?.parse_into::<f64>()
+}
+
+#[test]
+fn spec_examples() -> Result<()> {
+    let mut src = JsonParser::new(r"{
+        integer: 123,
+        withFractionPart: 123.456,
+        onlyFractionPart: .456,
+        withExponent: 123e-456,
+        hexadecimal: 0xdecaf,
+        leadingDecimalPoint: .8675309, andTrailing: 8675309.,
+        positiveSign: +1,
+        positiveInfinity: Infinity,
+        negativeInfinity: -Infinity,
+        notANumber: NaN,
+    }");
+    let out = src.parse()?;
+    let number = |key: &str| out.index(key).unwrap().parse_into::<f64>();
+
+    assert_eq!(Ok(123.0), number("integer"));
+    assert_eq!(Ok(123.456), number("withFractionPart"));
+    assert_eq!(Ok(0.456), number("onlyFractionPart"));
+    assert_eq!(Ok(0.0), number("withExponent"));
+    assert_eq!(Ok(912559.0), number("hexadecimal"));
+    assert_eq!(Ok(0.8675309), number("leadingDecimalPoint"));
+    assert_eq!(Ok(8675309.0), number("andTrailing"));
+    assert_eq!(Ok(1.0), number("positiveSign"));
+    assert_eq!(Ok(f64::INFINITY), number("positiveInfinity"));
+    assert_eq!(Ok(f64::NEG_INFINITY), number("negativeInfinity"));
+    assert!(number("notANumber").is_ok_and(f64::is_nan));
+    Ok(())
+}
+
+#[test]
+fn valid_numbers() {
+    let cases = [
+        // integer
+        ("0", 0.0), ("-0", -0.0), ("+0", 0.0), ("15", 15.0), ("-15", -15.0), ("+15", 15.0),
+        // decimal
+        ("1.5", 1.5), ("-1.5", -1.5), ("+1.5", 1.5),
+        (".5", 0.5), ("-.5", -0.5), ("+.5", 0.5),
+        ("5.", 5.0), ("-5.", -5.0), ("+5.", 5.0),
+        ("0.5", 0.5), ("0.", 0.0),
+        // exponent
+        ("1e2", 100.0), ("1E2", 100.0), ("1e+2", 100.0), ("1e-2", 0.01), ("-1e-2", -0.01),
+        ("1.2e3", 1200.0), (".5e1", 5.0), ("5.e1", 50.0), ("0e0", 0.0),
+        // hexadecimal
+        ("0x1", 1.0), ("0xF", 15.0), ("0xc8", 200.0), ("0XC8", 200.0), ("0xC8", 200.0), ("-0xC8", -200.0), ("+0xC8", 200.0),
+        // keywords
+        ("Infinity", f64::INFINITY), ("+Infinity", f64::INFINITY), ("-Infinity", f64::NEG_INFINITY),
+    ];
+
+    for (src, expected) in cases {
+        assert_eq!(Ok(expected), parse_number(src), "{src}");
+        // inside a container too
+        let array_src = format!("[{src}]");
+        let mut array = JsonParser::new(&array_src);
+        assert_eq!(Ok(expected), array.parse().and_then(|out| out.index(0).unwrap().parse_into::<f64>()), "[{src}]");
+    }
+    for src in ["NaN", "+NaN", "-NaN"] {
+        assert!(parse_number(src).is_ok_and(f64::is_nan), "{src}");
+    }
+}
+
+#[test]
+fn invalid_numbers() {
+    let invalid = |reason: &str| ErrorKind::InvalidNumber(reason.into());
+    let cases = [
+        // sign
+        ("- 5", invalid("'+' and '-' must be directly followed by a digit, '.', Infinity or NaN")),
+        ("+-5", invalid("'+' and '-' must be directly followed by a digit, '.', Infinity or NaN")),
+        ("--5", invalid("'+' and '-' must be directly followed by a digit, '.', Infinity or NaN")),
+        ("-", invalid("'+' and '-' must be directly followed by a digit, '.', Infinity or NaN")),
+        ("-Inf", invalid("'+' and '-' must be directly followed by a digit, '.', Infinity or NaN")),
+        ("-null", invalid("'+' and '-' must be directly followed by a digit, '.', Infinity or NaN")),
+        // decimal
+        (".", invalid("expect at least one digit after the decimal point")),
+        ("-.", invalid("expect at least one digit after the decimal point")),
+        ("1.2.3", invalid("unexpected character right after the number")),
+        ("..5", invalid("expect at least one digit after the decimal point")),
+        // leading zero (octal-looking)
+        ("00", invalid("a number cannot start with a leading zero")),
+        ("010", invalid("a number cannot start with a leading zero")),
+        ("-01.5", invalid("a number cannot start with a leading zero")),
+        // exponent
+        ("1e", invalid("expect at least one digit in the exponent")),
+        ("1e+", invalid("expect at least one digit in the exponent")),
+        (".5e-", invalid("expect at least one digit in the exponent")),
+        ("1e2.5", invalid("unexpected character right after the number")),
+        // hexadecimal
+        ("0x", invalid("expect at least one hexdigit after 0x")),
+        ("-0x", invalid("expect at least one hexdigit after 0x")),
+        ("0xG", invalid("expect at least one hexdigit after 0x")),
+        ("0x1G", invalid("unexpected character right after the number")),
+        ("0x1.5", invalid("unexpected character right after the number")),
+        // identifier right after the number
+        ("1a", invalid("unexpected character right after the number")),
+        ("Infinity5", ErrorKind::UnexpectedToken { expected: "value", found: "ident".into() }),
+    ];
+
+    for (src, expected) in cases {
+        let err = parse_number(src).expect_err(src);
+        assert_eq!(&expected, err.kind(), "{src}");
+    }
+
+    let err = parse_number("[1, +-5]").unwrap_err();
+    assert_eq!(&Span::new(4, 5), err.span());
+}
+
+#[test]
+fn keep_number_text() -> Result<()> {
+    let mut src = JsonParser::new("[-Infinity, +NaN, -0x80, 5., -.5, 1E+2]");
+    let out = src.parse()?;
+    let texts = out.parse_into::<Vec<JsonNumber>>()?;
+
+    assert_eq!(vec!["-Infinity", "+NaN", "-0x80", "5.", "-.5", "1E+2"], texts.iter().map(JsonNumber::as_str).collect::<Vec<_>>());
+    assert_eq!(Ok(i8::MIN), out.index(2).unwrap().parse_into::<i8>());
+    assert_eq!(Ok(5), out.index(3).unwrap().parse_into::<u8>());
+    assert_eq!(Ok(100), out.index(5).unwrap().parse_into::<u8>());
+    Ok(())
+}
+
+#[test]
+fn signed_keyword_is_not_a_key() -> Result<()> {
+    let mut src = JsonParser::new("{ Infinity: 1, NaN: 2 }");
+    let out = src.parse()?;
+    assert_eq!(Ok(2), out.index("NaN").unwrap().parse_into::<u8>());
+
+    for key in ["-Infinity", "+Infinity", "-NaN", "+NaN"] {
+        let src = format!("{{ {key}: 1 }}");
+        let err = JsonParser::new(&src).parse().expect_err(&src);
+        assert!(matches!(err.kind(), ErrorKind::UnexpectedToken { expected: "key or '}'", .. }), "{src}");
+        assert_eq!(&Span::new(2, 2 + key.len()), err.span(), "{src}");
+    }
+    Ok(())
+}
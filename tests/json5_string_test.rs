@@ -0,0 +1,87 @@
+use std::collections::BTreeMap;
+
+use jsode::prelude::*;
+
+// strings, whitespace and identifiers of https://spec.json5.org/#strings, #white-space and #identifiers
+
+#[test]
+fn line_continuation() -> Result<()> {
+    let src = "['one \\\ntwo', 'crlf \\\r\nend', 'cr \\\rend', 'ls \\\u{2028}end', 'ps \\\u{2029}end', 'raw \u{2028} separator']";
+    let out = JsonParser::new(src).parse()?.parse_into::<Vec<String>>()?;
+
+    assert_eq!(vec!["one two", "crlf end", "cr end", "ls end", "ps end", "raw \u{2028} separator"], out);
+    Ok(())
+}
+
+#[test]
+fn null_escape() -> Result<()> {
+    let out = JsonParser::new(r"['\0', 'a\0b', '\0\0']").parse()?.parse_into::<Vec<String>>()?;
+    assert_eq!(vec!["\0", "a\0b", "\0\0"], out);
+
+    for src in [r"'\01'", r"'a\00'", r"'\09'"] {
+        let err = JsonParser::new(src).parse().expect_err(src);
+        assert!(matches!(err.kind(), ErrorKind::InvalidEscape(escape) if escape.starts_with(r"\0")), "{src}: {err:?}");
+    }
+    let err = JsonParser::new(r"'\01'").parse().unwrap_err();
+    assert_eq!(&ErrorKind::InvalidEscape(r"\01".into()), err.kind());
+    assert_eq!((1, 4), (err.span().start, err.span().end));
+    Ok(())
+}
+
+#[test]
+fn unicode_whitespace() -> Result<()> {
+    let separators = ["\u{0B}", "\u{0C}", "\u{A0}", "\u{2028}", "\u{2029}", "\u{FEFF}", "\u{1680}", "\u{2003}", "\u{3000}"];
+
+    for separator in separators {
+        let src = format!("{separator}{{{separator}a{separator}:{separator}[1,{separator}2]{separator}}}{separator}");
+        let mut parser = JsonParser::new(&src);
+        let out = parser.parse()?;
+        assert_eq!(Ok(vec![1, 2]), out.index("a").unwrap().parse_into::<Vec<u8>>(), "{separator:?}");
+    }
+    Ok(())
+}
+
+#[test]
+fn unicode_identifiers() -> Result<()> {
+    let mut src = JsonParser::new("{ $price: 1, _id: 2, café: 3, ünïcödé: 4, π: 5, a‿b: 6, x\u{200D}y: 7, a$1: 8 }");
+    let out = src.parse()?;
+
+    assert_eq!(vec!["$price", "_id", "café", "ünïcödé", "π", "a‿b", "x\u{200D}y", "a$1"], out.keys().collect::<Vec<_>>());
+    assert_eq!(Ok(1), out.index("$price").unwrap().parse_into::<u8>());
+    assert_eq!(Ok(3), out.index("café").unwrap().parse_into::<u8>());
+    assert!(JsonParser::new("{ 1a: 1 }").parse().is_err());
+    assert!(JsonParser::new("{ a-b: 1 }").parse().is_err());
+    Ok(())
+}
+
+#[test]
+fn escaped_identifier_key() -> Result<()> {
+    let mut src = JsonParser::new(r"{ \u0061: 1, \u0024_: 2, a\u0062c: 3, 'd\u0065f': 4, \u0074rue: 5 }");
+    let out = src.parse()?;
+
+    assert_eq!(vec!["a", "$_", "abc", "def", "true"], out.keys().collect::<Vec<_>>());
+    assert_eq!(Ok(1), out.index("a").unwrap().parse_into::<u8>());
+    assert_eq!(Ok(3), out.index("abc").unwrap().parse_into::<u8>());
+    assert_eq!(Ok(4), out.index("def").unwrap().parse_into::<u8>());
+    assert_eq!(Ok(5), out.index("true").unwrap().parse_into::<u8>());
+    assert_eq!(BTreeMap::from([("$_".to_owned(), 2), ("a".to_owned(), 1), ("abc".to_owned(), 3), ("def".to_owned(), 4), ("true".to_owned(), 5)]), out.parse_into::<BTreeMap<String, u8>>()?);
+    Ok(())
+}
+
+#[test]
+fn invalid_identifier_escape() {
+    let cases = [r"{ \u0031a: 1 }", r"{ a\u002D: 1 }", r"{ \u00: 1 }", r"{ \x61: 1 }", r"{ \uD800: 1 }"];
+
+    for src in cases {
+        let err = JsonParser::new(src).parse().expect_err(src);
+        assert!(matches!(err.kind(), ErrorKind::InvalidEscape(_)), "{src}: {err:?}");
+    }
+}
+
+#[test]
+fn serialize_unicode_key() {
+    let map = BTreeMap::from([("$price", 1), ("café", 2), ("a-b", 3)]);
+
+    assert_eq!(r#"{$price:1,"a-b":3,café:2}"#, map.to_json_with(&WriterOptions::json5()).unwrap());
+    assert_eq!(r#"{"$price":1,"a-b":3,"café":2}"#, map.to_json().unwrap());
+}
@@ -0,0 +1,249 @@
+mod common;
+
+use std::time::Duration;
+
+use common::parse;
+use jsode::prelude::*;
+
+#[derive(Deserialize, Serialize, PartialEq, Debug)]
+#[jsode(rename_all = "camelCase")]
+struct Server {
+    host_name: String,
+    #[jsode(default = "default_port")]
+    listen_port: u16,
+    #[jsode(default)]
+    max_retries: u8,
+    #[jsode(rename = "TLS", alias = "ssl", alias = "https")]
+    tls: bool,
+    #[jsode(skip)]
+    connections: Vec<u32>,
+    r#type: Option<String>,
+}
+
+fn default_port() -> u16 {
+    8080
+}
+
+#[derive(Deserialize, PartialEq, Debug)]
+#[jsode(deny_unknown_fields)]
+struct Point {
+    x: i32,
+    #[jsode(alias = "ordinate")]
+    y: i32,
+    #[jsode(skip)]
+    z: i32,
+}
+
+#[test]
+fn rename_all_fields() -> Result<()> {
+    let server = parse::<Server>("{ hostName: 'localhost', listenPort: 80, maxRetries: 3, TLS: true, type: 'proxy' }")?;
+
+    assert_eq!(Server {
+        host_name: "localhost".into(),
+        listen_port: 80,
+        max_retries: 3,
+        tls: true,
+        connections: vec![],
+        r#type: Some("proxy".into()),
+    }, server);
+    assert_eq!(r#"{"hostName":"localhost","listenPort":80,"maxRetries":3,"TLS":true,"type":"proxy"}"#, server.to_json().unwrap());
+    Ok(())
+}
+
+#[test]
+fn fill_missing_fields_with_default() -> Result<()> {
+    let server = parse::<Server>("{ hostName: 'localhost', TLS: false }")?;
+
+    assert_eq!(8080, server.listen_port);
+    assert_eq!(0, server.max_retries);
+    assert_eq!(None, server.r#type);
+
+    let err = parse::<Server>("{ hostName: 'localhost', listenPort: 'x', TLS: false }").unwrap_err();
+    assert_eq!(&ErrorKind::TypeMismatch { expected: "u16".into(), found: "string".into() }, err.kind());
+    Ok(())
+}
+
+#[test]
+fn read_field_from_alias() -> Result<()> {
+    assert!(parse::<Server>("{ hostName: 'a', ssl: true }")?.tls);
+    assert!(parse::<Server>("{ hostName: 'a', https: true }")?.tls);
+    // the name wins over its aliases
+    assert!(!parse::<Server>("{ hostName: 'a', https: true, TLS: false }")?.tls);
+
+    let err = parse::<Server>("{ hostName: 'a', tls: true }").unwrap_err();
+    assert_eq!(&ErrorKind::MissingKey("TLS".into()), err.kind());
+    Ok(())
+}
+
+#[test]
+fn skip_field() -> Result<()> {
+    let server = parse::<Server>("{ hostName: 'a', TLS: true, connections: [1, 2] }")?;
+
+    assert!(server.connections.is_empty());
+    assert_eq!(Point { x: 1, y: 2, z: 0 }, parse("{ x: 1, ordinate: 2 }")?);
+    Ok(())
+}
+
+#[test]
+fn deny_unknown_fields() {
+    let err = parse::<Point>("{ x: 1, y: 2, z: 3 }").unwrap_err();
+
+    assert_eq!(&ErrorKind::UnknownKey { found: "z".into(), expected: &["x", "y", "ordinate"] }, err.kind());
+    assert_eq!("unknown key `z`, expect one of `x`, `y`, `ordinate`", err.to_string());
+    assert_eq!(&Span::new(14, 15), err.span());
+}
+
+#[derive(Deserialize, Serialize, PartialEq, Debug)]
+struct Pagination {
+    page: u32,
+    per_page: u32,
+}
+
+#[derive(Deserialize, Serialize, PartialEq, Debug)]
+struct Users {
+    users: Vec<String>,
+    #[jsode(flatten)]
+    pagination: Pagination,
+}
+
+#[derive(Deserialize, PartialEq, Debug)]
+struct Repo {
+    name: String,
+    #[jsode(path = "meta.owner.id")]
+    owner_id: u64,
+    #[jsode(path = "meta.tags.0")]
+    first_tag: Option<String>,
+    #[jsode(path = "meta.stars", default)]
+    stars: u32,
+}
+
+#[test]
+fn flatten_struct() -> Result<()> {
+    let users = parse::<Users>("{ users: ['a', 'b'], page: 2, per_page: 10 }")?;
+
+    assert_eq!(Users { users: vec!["a".into(), "b".into()], pagination: Pagination { page: 2, per_page: 10 } }, users);
+    assert_eq!(r#"{"users":["a","b"],"page":2,"per_page":10}"#, users.to_json().unwrap());
+
+    let err = parse::<Users>("{ users: [], page: 2 }").unwrap_err();
+    assert_eq!(&ErrorKind::MissingKey("per_page".into()), err.kind());
+    Ok(())
+}
+
+#[derive(Serialize)]
+struct FlattenNumber {
+    a: u8,
+    #[jsode(flatten)]
+    b: u8,
+}
+
+#[test]
+fn flatten_non_object_fails() {
+    // a number has no properties to write into the parent object
+    assert_eq!(Err(std::fmt::Error), FlattenNumber { a: 1, b: 2 }.to_json());
+    assert!(FlattenNumber { a: 1, b: 2 }.write_json(Vec::new(), &WriterOptions::new()).is_err());
+}
+
+#[test]
+fn read_field_from_path() -> Result<()> {
+    let repo = parse::<Repo>("{ name: 'jsode', meta: { owner: { id: 7 }, tags: ['json', 'json5'] } }")?;
+    assert_eq!(Repo { name: "jsode".into(), owner_id: 7, first_tag: Some("json".into()), stars: 0 }, repo);
+
+    let repo = parse::<Repo>("{ name: 'jsode', meta: { owner: { id: 7 }, stars: 5 } }")?;
+    assert_eq!(None, repo.first_tag);
+    assert_eq!(5, repo.stars);
+
+    let err = parse::<Repo>("{ name: 'jsode', meta: { owner: {} } }").unwrap_err();
+    assert_eq!(&ErrorKind::MissingKey("meta.owner.id".into()), err.kind());
+    let err = parse::<Repo>("{ name: 'jsode', meta: { owner: { id: 7 }, tags: [1] } }").unwrap_err();
+    assert_eq!("$.meta.tags[0]", err.path().to_string());
+    Ok(())
+}
+
+#[derive(PartialEq, Debug)]
+struct Date {
+    year: u16,
+    month: u8,
+    day: u8,
+}
+
+// `2024-02-29`
+fn parse_date(out: &JsonOutput<'_, '_>) -> Result<Date> {
+    let text = out.parse_into::<String>()?;
+    let invalid = || JsonError::custom(format!("invalid date `{text}`"), out.get_span());
+    let mut parts = text.splitn(3, '-').map(str::parse::<u16>);
+    match (parts.next(), parts.next(), parts.next()) {
+        (Some(Ok(year)), Some(Ok(month @ 1..=12)), Some(Ok(day @ 1..=31))) => Ok(Date { year, month: month as u8, day: day as u8 }),
+        _ => Err(invalid()),
+    }
+}
+
+// durations written as `90s`
+mod seconds {
+    use std::{fmt, time::Duration};
+    use jsode::prelude::*;
+
+    pub fn deserialize(out: &JsonOutput<'_, '_>) -> Result<Duration> {
+        let text = out.parse_into::<String>()?;
+        text.strip_suffix('s')
+            .and_then(|secs| secs.parse().ok())
+            .map(Duration::from_secs)
+            .ok_or_else(|| JsonError::custom(format!("invalid duration `{text}`"), out.get_span()))
+    }
+
+    pub fn serialize<W: fmt::Write>(value: &Duration, writer: &mut JsonWriter<W>) -> fmt::Result {
+        writer.write_str(&format!("{}s", value.as_secs()))
+    }
+}
+
+#[derive(Deserialize, Serialize, PartialEq, Debug)]
+struct Job {
+    #[jsode(with = "seconds")]
+    timeout: Duration,
+    #[jsode(with = "seconds", default)]
+    retry_after: Duration,
+    #[jsode(deserialize_with = "parse_date", skip)]
+    started: Option<Date>,
+}
+
+#[derive(Deserialize, PartialEq, Debug)]
+struct Release {
+    #[jsode(deserialize_with = "parse_date")]
+    date: Date,
+}
+
+#[derive(Deserialize, PartialEq, Debug)]
+struct Listener {
+    #[jsode(msg = "`port` must be a number between 0 and 65535")]
+    port: u16,
+    #[msg = "`host` is required"]
+    host: String,
+}
+
+#[test]
+fn custom_deserialize_function() -> Result<()> {
+    let job = parse::<Job>("{ timeout: '90s' }")?;
+    assert_eq!(Job { timeout: Duration::from_secs(90), retry_after: Duration::ZERO, started: None }, job);
+    assert_eq!(r#"{"timeout":"90s","retry_after":"0s"}"#, job.to_json().unwrap());
+
+    let release = parse::<Release>("{ date: '2024-02-29' }")?;
+    assert_eq!(Release { date: Date { year: 2024, month: 2, day: 29 } }, release);
+
+    let err = parse::<Release>("{ date: '2024-13-01' }").unwrap_err();
+    assert_eq!("invalid date `2024-13-01`", err.to_string());
+    assert_eq!(&Span::new(9, 19), err.span());
+    let err = parse::<Job>("{ timeout: 90 }").unwrap_err();
+    assert_eq!(&ErrorKind::TypeMismatch { expected: "String".into(), found: "integer".into() }, err.kind());
+    Ok(())
+}
+
+#[test]
+fn custom_error_message() {
+    let err = parse::<Listener>("{ port: 'http', host: 'localhost' }").unwrap_err();
+    assert_eq!("`port` must be a number between 0 and 65535", err.to_string());
+    assert_eq!(&ErrorKind::TypeMismatch { expected: "u16".into(), found: "string".into() }, err.kind());
+    assert_eq!(&Span::new(9, 13), err.span());
+
+    let err = parse::<Listener>("{ port: 80 }").unwrap_err();
+    assert_eq!("`host` is required", err.to_string());
+    assert_eq!(&ErrorKind::MissingKey("host".into()), err.kind());
+}
@@ -1,5 +1,8 @@
-use std::marker::PhantomData;
+mod common;
 
+use std::{borrow::Cow, collections::{BTreeMap, HashMap}, marker::PhantomData};
+
+use common::parse;
 use jsode::prelude::*;
 
 #[derive(Deserialize, PartialEq, Debug)]
@@ -56,3 +59,246 @@ fn parse_color() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn parse_null_as_none() -> Result<()> {
+    let mut color = JsonParser::new("{ red: 9, green: 10, blue: 11, alpha: null, hue: [] }");
+
+    let res = color.parse()?.parse_into::<Color<'static, String>>()?;
+
+    assert_eq!(None, res.alpha);
+    Ok(())
+}
+
+#[test]
+fn reject_null_on_required_field() -> Result<()> {
+    let mut color = JsonParser::new("{ red: null, green: 10, blue: 11, hue: [null] }");
+    let out = color.parse()?;
+
+    let err = out.parse_into::<Color<'static, String>>().unwrap_err();
+    assert_eq!("cannot convert type null to type u8", err.to_string());
+
+    let err = out.index("hue").unwrap().parse_into::<Vec<Hue>>().unwrap_err();
+    assert_eq!(&ErrorKind::TypeMismatch { expected: "Hue".into(), found: "null".into() }, err.kind());
+    Ok(())
+}
+
+#[test]
+fn error_path_of_nested_field() -> Result<()> {
+    let src = "{ red: 9, green: 10, blue: 11, hue: [{ h: 1 }, { h: 'x' }] }";
+    let err = JsonParser::new(src).parse()?.parse_into::<Color<'static, String>>().unwrap_err();
+
+    assert_eq!("$.hue[1].h", err.path().to_string());
+    assert_eq!(&ErrorKind::TypeMismatch { expected: "u8".into(), found: "string".into() }, err.kind());
+    assert_eq!("x", &src[err.span().start..err.span().end]);
+    Ok(())
+}
+
+#[test]
+fn missing_key_points_at_parent_object() -> Result<()> {
+    let src = "{ red: 9, green: 10, blue: 11, hue: [{ h: 1 }, { x: 2 }] }";
+    let err = JsonParser::new(src).parse()?.parse_into::<Color<'static, String>>().unwrap_err();
+
+    assert_eq!(&ErrorKind::MissingKey("h".into()), err.kind());
+    assert_eq!("$.hue[1]", err.path().to_string());
+    assert_eq!("{ x: 2 }", &src[err.span().start..err.span().end]);
+    Ok(())
+}
+
+#[derive(Deserialize, PartialEq, Debug)]
+struct Palette {
+    colors: HashMap<String, Hue>,
+    ports: BTreeMap<u16, String>,
+}
+
+#[test]
+fn parse_map() -> Result<()> {
+    let mut palette = JsonParser::new("{ colors: { red: { h: 0 }, 'sky blue': { h: 200 } }, ports: { '443': 'https', '80': 'http' } }");
+
+    let res = palette.parse()?.parse_into::<Palette>()?;
+
+    assert_eq!(HashMap::from([("red".to_string(), Hue::new(0)), ("sky blue".to_string(), Hue::new(200))]), res.colors);
+    assert_eq!(vec![(80, "http"), (443, "https")], res.ports.iter().map(|(k, v)| (*k, v.as_str())).collect::<Vec<_>>());
+    Ok(())
+}
+
+#[test]
+fn map_error_names_the_failing_key() -> Result<()> {
+    let mut src = JsonParser::new("{ colors: { red: { h: 0 }, blue: { h: 'oops' } }, ports: {} }");
+    let err = src.parse()?.index("colors").unwrap().parse_into::<HashMap<String, Hue>>().unwrap_err();
+    assert_eq!("$.blue.h", err.path().to_string());
+
+    let mut src = JsonParser::new("{ http: 80 }");
+    let err = src.parse()?.parse_into::<HashMap<u16, u16>>().unwrap_err();
+    assert_eq!(&ErrorKind::InvalidKey { key: "http".into(), reason: "invalid digit found in string".into() }, err.kind());
+    assert_eq!("$.http", err.path().to_string());
+
+    let mut src = JsonParser::new("[1]");
+    let err = src.parse()?.parse_into::<BTreeMap<String, u8>>().unwrap_err();
+    assert_eq!(&ErrorKind::TypeMismatch { expected: "map".into(), found: "array".into() }, err.kind());
+    Ok(())
+}
+
+#[derive(Deserialize, PartialEq, Debug)]
+struct User<'a> {
+    name: &'a str,
+    bio: Cow<'a, str>,
+    tags: Vec<&'a str>,
+}
+
+#[test]
+fn parse_borrowed_fields() -> Result<()> {
+    // the source is owned by this function, so nothing here is `'static`
+    let source = String::from(r"{ name: 'jsode', bio: 'zero\u002Dcopy', tags: ['json', 'parser'] }");
+    let mut parser = JsonParser::new(&source);
+    let out = parser.parse()?;
+    let user = out.parse_into::<User>()?;
+
+    assert_eq!(User { name: "jsode", bio: Cow::Borrowed("zero-copy"), tags: vec!["json", "parser"] }, user);
+    assert!(matches!(user.bio, Cow::Owned(_)));
+
+    let source = String::from(r"{ name: 'caf\u00e9', bio: '', tags: [] }");
+    let mut parser = JsonParser::new(&source);
+    let err = parser.parse()?.parse_into::<User>().unwrap_err();
+    assert_eq!(&ErrorKind::TypeMismatch { expected: "&str".into(), found: "string with escape sequence".into() }, err.kind());
+    Ok(())
+}
+
+#[derive(Deserialize, PartialEq, Debug)]
+struct UserId(u64);
+
+#[derive(Deserialize, PartialEq, Debug)]
+struct Rgb(u8, u8, Option<u8>);
+
+#[derive(Deserialize, PartialEq, Debug)]
+struct Account {
+    id: UserId,
+    color: Rgb,
+    friends: Vec<UserId>,
+}
+
+#[test]
+fn parse_tuple_struct() -> Result<()> {
+    let mut account = JsonParser::new("{ id: 7, color: [1, 2, null], friends: [8, 9] }");
+
+    let res = account.parse()?.parse_into::<Account>()?;
+
+    assert_eq!(Account { id: UserId(7), color: Rgb(1, 2, None), friends: vec![UserId(8), UserId(9)] }, res);
+    Ok(())
+}
+
+#[test]
+fn reject_tuple_struct_of_wrong_arity() -> Result<()> {
+    let mut src = JsonParser::new("{ short: [1, 2], long: [1, 2, 3, 4], obj: { r: 1 }, id: '7' }");
+    let out = src.parse()?;
+
+    let err = out.index("short").unwrap().parse_into::<Rgb>().unwrap_err();
+    assert_eq!(&ErrorKind::InvalidLength { expected: 3, found: 2 }, err.kind());
+    assert_eq!("invalid length 2, expect an array of 3 items", err.to_string());
+
+    let err = out.index("long").unwrap().parse_into::<Rgb>().unwrap_err();
+    assert_eq!(&ErrorKind::InvalidLength { expected: 3, found: 4 }, err.kind());
+
+    let err = out.index("obj").unwrap().parse_into::<Rgb>().unwrap_err();
+    assert_eq!(&ErrorKind::TypeMismatch { expected: "Rgb".into(), found: "object".into() }, err.kind());
+
+    let err = out.index("id").unwrap().parse_into::<UserId>().unwrap_err();
+    assert_eq!(&ErrorKind::TypeMismatch { expected: "u64".into(), found: "string".into() }, err.kind());
+    Ok(())
+}
+
+#[derive(Deserialize, PartialEq, Debug)]
+struct Server {
+    addr: std::net::SocketAddr,
+    ip: std::net::IpAddr,
+    root: std::path::PathBuf,
+    workers: std::num::NonZeroU8,
+    flag: char,
+    id: u128,
+    offset: i128,
+    origin: (i32, i32, String),
+    rgb: [u8; 3],
+    tags: std::collections::BTreeSet<String>,
+    queue: std::collections::VecDeque<u8>,
+    ids: std::collections::HashSet<u32>,
+    shared: std::rc::Rc<Hue>,
+    boxed: Box<Hue>,
+    nothing: (),
+}
+
+#[test]
+fn parse_std_types() -> Result<()> {
+    let src = r#"{
+        addr: '127.0.0.1:8080', ip: '::1', root: '/var/www', workers: 4, flag: 'y',
+        id: 340282366920938463463374607431768211455, offset: -170141183460469231731687303715884105728,
+        origin: [1, -2, 'top'], rgb: [255, 0, 128], tags: ['b', 'a', 'b'], queue: [3, 1], ids: [7, 7, 9],
+        shared: { h: 1 }, boxed: { h: 2 }, nothing: null,
+    }"#;
+    let server = JsonParser::new(src).parse()?.parse_into::<Server>()?;
+
+    assert_eq!("127.0.0.1:8080".parse::<std::net::SocketAddr>().unwrap(), server.addr);
+    assert_eq!(std::net::IpAddr::from(std::net::Ipv6Addr::LOCALHOST), server.ip);
+    assert_eq!(std::path::PathBuf::from("/var/www"), server.root);
+    assert_eq!(4, server.workers.get());
+    assert_eq!('y', server.flag);
+    assert_eq!(u128::MAX, server.id);
+    assert_eq!(i128::MIN, server.offset);
+    assert_eq!((1, -2, "top".to_string()), server.origin);
+    assert_eq!([255, 0, 128], server.rgb);
+    assert_eq!(vec!["a", "b"], server.tags.iter().collect::<Vec<_>>());
+    assert_eq!(vec![3, 1], Vec::from(server.queue));
+    assert_eq!(2, server.ids.len());
+    assert_eq!(Hue::new(1), *server.shared);
+    assert_eq!(Hue::new(2), *server.boxed);
+    Ok(())
+}
+
+#[test]
+fn reject_invalid_std_types() -> Result<()> {
+    let err = parse::<[u8; 3]>("[1, 2]").unwrap_err();
+    assert_eq!(&ErrorKind::InvalidLength { expected: 3, found: 2 }, err.kind());
+    let err = parse::<(u8, String)>("[1, 2]").unwrap_err();
+    assert_eq!(&ErrorKind::TypeMismatch { expected: "String".into(), found: "integer".into() }, err.kind());
+    assert_eq!("$[1]", err.path().to_string());
+    let err = parse::<(u8, u8)>("{}").unwrap_err();
+    assert_eq!(&ErrorKind::TypeMismatch { expected: "tuple".into(), found: "object".into() }, err.kind());
+    let err = parse::<std::num::NonZeroU32>("0").unwrap_err();
+    assert_eq!(&ErrorKind::InvalidValue { ty: "NonZeroU32", reason: "expect a non-zero number".into() }, err.kind());
+    assert_eq!("invalid value for type NonZeroU32, expect a non-zero number", err.to_string());
+    let err = parse::<u128>("340282366920938463463374607431768211456").unwrap_err();
+    assert_eq!(&ErrorKind::IntegerOverflow("u128"), err.kind());
+    let err = parse::<char>("'ab'").unwrap_err();
+    assert_eq!("invalid value for type char, expect a single character, found 2", err.to_string());
+    let err = parse::<std::net::IpAddr>("'localhost'").unwrap_err();
+    assert_eq!(&ErrorKind::InvalidValue { ty: "IpAddr", reason: "invalid IP address syntax".into() }, err.kind());
+    let err = parse::<std::net::IpAddr>("1").unwrap_err();
+    assert_eq!(&ErrorKind::TypeMismatch { expected: "IpAddr".into(), found: "integer".into() }, err.kind());
+    let err = parse::<()>("0").unwrap_err();
+    assert_eq!(&ErrorKind::TypeMismatch { expected: "()".into(), found: "integer".into() }, err.kind());
+    Ok(())
+}
+
+#[derive(Deserialize, PartialEq, Debug)]
+struct Wrap<T> {
+    inner: T,
+}
+
+#[derive(Deserialize, PartialEq, Debug)]
+struct Pair<K, V>(K, Vec<V>);
+
+#[derive(Deserialize, PartialEq, Debug)]
+enum Either<L, R> {
+    Left(L),
+    Right { value: R },
+}
+
+#[test]
+fn deserialize_generic_struct() -> Result<()> {
+    assert_eq!(Wrap { inner: 1u8 }, parse::<Wrap<u8>>("{ inner: 1 }")?);
+    assert_eq!(Wrap { inner: Hue::new(2) }, parse::<Wrap<Hue>>("{ inner: { h: 2 } }")?);
+    assert_eq!(Pair("a".to_string(), vec![true]), parse::<Pair<String, bool>>("['a', [true]]")?);
+    assert_eq!(Either::Right { value: 3 }, parse::<Either<String, u8>>("{ Right: { value: 3 } }")?);
+    let err = parse::<Wrap<u8>>("{ inner: 'a' }").unwrap_err();
+    assert_eq!("$.inner", err.path().to_string());
+    Ok(())
+}
@@ -0,0 +1,141 @@
+mod common;
+
+use common::parse;
+use jsode::prelude::*;
+
+#[derive(Deserialize, PartialEq, Debug)]
+enum Shape {
+    Empty,
+    #[jsode(rename = "dot", alias = "point")]
+    Point,
+    Circle { radius: u32 },
+    Square(u32),
+    Rect(u32, u32),
+}
+
+#[derive(Deserialize, PartialEq, Debug)]
+struct Login {
+    user: String,
+}
+
+#[derive(Deserialize, PartialEq, Debug)]
+#[jsode(tag = "type")]
+enum Event {
+    Ping,
+    Move { x: i32, y: i32 },
+    Login(Login),
+}
+
+#[derive(Deserialize, PartialEq, Debug)]
+#[jsode(tag = "t", content = "c")]
+enum Message {
+    Quit,
+    Text(String),
+    Pair(u8, u8),
+    Move { x: i32 },
+}
+
+#[derive(Deserialize, PartialEq, Debug)]
+#[jsode(untagged)]
+enum Value {
+    Nothing,
+    Flag(bool),
+    Number(i64),
+    Pair(i64, i64),
+    Named { name: String },
+}
+
+#[test]
+fn externally_tagged() -> Result<()> {
+    assert_eq!(Shape::Empty, parse("'Empty'")?);
+    assert_eq!(Shape::Point, parse("{ dot: null }")?);
+    assert_eq!(Shape::Point, parse("'point'")?);
+    assert_eq!(Shape::Circle { radius: 2 }, parse("{ Circle: { radius: 2 } }")?);
+    assert_eq!(Shape::Square(3), parse("{ Square: 3 }")?);
+    assert_eq!(Shape::Rect(3, 4), parse("{ Rect: [3, 4] }")?);
+
+    let err = parse::<Shape>("{ Circle: {}, Square: 3 }").unwrap_err();
+    assert_eq!(&ErrorKind::TypeMismatch { expected: "Shape".into(), found: "object of 2 keys".into() }, err.kind());
+    let err = parse::<Shape>("{ Rect: [3] }").unwrap_err();
+    assert_eq!(&ErrorKind::InvalidLength { expected: 2, found: 1 }, err.kind());
+    let err = parse::<Shape>("'Circle'").unwrap_err();
+    assert!(matches!(err.kind(), ErrorKind::UnknownVariant { found, .. } if found == "Circle"));
+    Ok(())
+}
+
+#[test]
+fn internally_tagged() -> Result<()> {
+    assert_eq!(Event::Ping, parse("{ type: 'Ping' }")?);
+    assert_eq!(Event::Move { x: 1, y: -1 }, parse("{ type: 'Move', x: 1, y: -1 }")?);
+    assert_eq!(Event::Login(Login { user: "me".into() }), parse("{ user: 'me', type: 'Login' }")?);
+
+    let err = parse::<Event>("{ x: 1 }").unwrap_err();
+    assert_eq!(&ErrorKind::MissingKey("type".into()), err.kind());
+    let err = parse::<Event>("'Ping'").unwrap_err();
+    assert_eq!(&ErrorKind::TypeMismatch { expected: "Event".into(), found: "string".into() }, err.kind());
+    Ok(())
+}
+
+#[test]
+fn adjacently_tagged() -> Result<()> {
+    assert_eq!(Message::Quit, parse("{ t: 'Quit' }")?);
+    assert_eq!(Message::Text("hi".into()), parse("{ t: 'Text', c: 'hi' }")?);
+    assert_eq!(Message::Pair(1, 2), parse("{ c: [1, 2], t: 'Pair' }")?);
+    assert_eq!(Message::Move { x: 5 }, parse("{ t: 'Move', c: { x: 5 } }")?);
+
+    let err = parse::<Message>("{ t: 'Text' }").unwrap_err();
+    assert_eq!(&ErrorKind::MissingKey("c".into()), err.kind());
+    Ok(())
+}
+
+#[test]
+fn untagged() -> Result<()> {
+    assert_eq!(Value::Nothing, parse("null")?);
+    assert_eq!(Value::Flag(true), parse("true")?);
+    assert_eq!(Value::Number(-7), parse("-7")?);
+    assert_eq!(Value::Pair(1, 2), parse("[1, 2]")?);
+    assert_eq!(Value::Named { name: "x".into() }, parse("{ name: 'x' }")?);
+
+    let err = parse::<Value>("'text'").unwrap_err();
+    assert_eq!(&ErrorKind::UnmatchedVariant("Value"), err.kind());
+    assert_eq!("data did not match any variant of enum Value", err.to_string());
+    Ok(())
+}
+
+#[test]
+fn unknown_variant_lists_accepted_names() {
+    let err = parse::<Event>("{ type: 'Logout' }").unwrap_err();
+
+    assert_eq!(&ErrorKind::UnknownVariant { found: "Logout".into(), expected: &["Ping", "Move", "Login"] }, err.kind());
+    assert_eq!("unknown variant `Logout`, expect one of `Ping`, `Move`, `Login`", err.to_string());
+    assert_eq!(&Span::new(9, 15), err.span());
+}
+
+#[derive(Deserialize, PartialEq, Debug)]
+#[jsode(tag = "kind", rename_all = "snake_case")]
+enum Command {
+    SignIn { user: String },
+    SignOut,
+}
+
+#[test]
+fn rename_all_variants() -> Result<()> {
+    assert_eq!(Command::SignIn { user: "me".into() }, parse("{ kind: 'sign_in', user: 'me' }")?);
+    assert_eq!(Command::SignOut, parse("{ kind: 'sign_out' }")?);
+
+    let err = parse::<Command>("{ kind: 'SignOut' }").unwrap_err();
+    assert_eq!(&ErrorKind::UnknownVariant { found: "SignOut".into(), expected: &["sign_in", "sign_out"] }, err.kind());
+    Ok(())
+}
+
+#[test]
+fn error_path_goes_through_variant() {
+    let err = parse::<Shape>("{ Circle: { radius: -1 } }").unwrap_err();
+    assert_eq!("$.Circle.radius", err.path().to_string());
+
+    let err = parse::<Message>("{ t: 'Pair', c: [1, 'x'] }").unwrap_err();
+    assert_eq!("$.c[1]", err.path().to_string());
+
+    let err = parse::<Event>("{ type: 'Move', x: 1, y: true }").unwrap_err();
+    assert_eq!("$.y", err.path().to_string());
+}
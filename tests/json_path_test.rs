@@ -0,0 +1,97 @@
+use jsode::prelude::*;
+
+const SOURCE: &str = r#"{
+    servers: [
+        { host: 'localhost', ports: [80, 443] },
+        { host: 'example.com', tags: { 'a/b': 1, 'm~n': 2, 'x.y': 3, '': 4 } },
+    ],
+    "first name": 'jsode',
+}"#;
+
+#[test]
+fn json_pointer() -> Result<()> {
+    let mut src = JsonParser::new(SOURCE);
+    let ast = src.parse()?;
+
+    assert_eq!(Ok(SOURCE), ast.pointer("")?.to_slice());
+    assert_eq!(Ok("localhost"), ast.pointer("/servers/0/host")?.to_slice());
+    assert_eq!(Ok(443), ast.pointer("/servers/0/ports/1")?.parse_into::<u16>());
+    assert_eq!(Ok(1), ast.pointer("/servers/1/tags/a~1b")?.parse_into::<u8>());
+    assert_eq!(Ok(2), ast.pointer("/servers/1/tags/m~0n")?.parse_into::<u8>());
+    assert_eq!(Ok(4), ast.pointer("/servers/1/tags/")?.parse_into::<u8>());
+    assert_eq!(Ok("jsode".to_string()), ast.pointer("/first name")?.parse_into::<String>());
+    Ok(())
+}
+
+#[test]
+fn dotted_path() -> Result<()> {
+    let mut src = JsonParser::new(SOURCE);
+    let ast = src.parse()?;
+
+    assert_eq!(Ok(SOURCE), ast.get_path("")?.to_slice());
+    assert_eq!(Ok("localhost"), ast.get_path("servers[0].host")?.to_slice());
+    assert_eq!(Ok("localhost"), ast.get_path("servers.0.host")?.to_slice());
+    assert_eq!(Ok("localhost"), ast.get_path("$.servers[0].host")?.to_slice());
+    assert_eq!(Ok(443), ast.get_path("servers[0].ports[1]")?.parse_into::<u16>());
+    assert_eq!(Ok(3), ast.get_path("servers[1].tags['x.y']")?.parse_into::<u8>());
+    assert_eq!(Ok("jsode".to_string()), ast.get_path(r#"["first name"]"#)?.parse_into::<String>());
+    // relative to any value
+    let server = ast.get_path("servers[1]")?;
+    assert_eq!(Ok(1), server.get_path("tags['a/b']")?.parse_into::<u8>());
+    Ok(())
+}
+
+#[test]
+fn path_reaching_the_path_of_an_error() -> Result<()> {
+    let mut src = JsonParser::new("{ hue: [{ h: 1 }, { h: 'x' }] }");
+    let ast = src.parse()?;
+
+    let err = ast.get_path("hue")?.parse_into::<Vec<std::collections::HashMap<String, u8>>>().unwrap_err();
+    assert_eq!("$[1].h", err.path().to_string());
+    assert_eq!(Ok("x"), ast.get_path("hue")?.get_path(&err.path().to_string())?.to_slice());
+    Ok(())
+}
+
+#[test]
+fn name_the_missing_segment() -> Result<()> {
+    let mut src = JsonParser::new(SOURCE);
+    let ast = src.parse()?;
+
+    let not_found = |path: &str, segment: &str| ErrorKind::PathNotFound { path: path.into(), segment: segment.into() };
+    let cases = [
+        (ast.pointer("/servers/2/host"), not_found("/servers/2/host", "2"), "$.servers"),
+        (ast.pointer("/servers/0/port"), not_found("/servers/0/port", "port"), "$.servers[0]"),
+        (ast.pointer("/servers/01"), not_found("/servers/01", "01"), "$.servers"),
+        (ast.pointer("/servers/-"), not_found("/servers/-", "-"), "$.servers"),
+        (ast.pointer("/servers/0/host/0"), not_found("/servers/0/host/0", "0"), "$.servers[0].host"),
+        (ast.pointer("/servers/1/tags/a/b"), not_found("/servers/1/tags/a/b", "a"), "$.servers[1].tags"),
+        (ast.get_path("servers[1].tags.x.y"), not_found("servers[1].tags.x.y", "x"), "$.servers[1].tags"),
+        (ast.get_path("nothing"), not_found("nothing", "nothing"), "$"),
+    ];
+
+    for (result, kind, path) in cases {
+        let err = result.unwrap_err();
+        assert_eq!(&kind, err.kind());
+        assert_eq!(path, err.path().to_string());
+    }
+
+    let err = ast.pointer("/servers/0/ports/2").unwrap_err();
+    assert_eq!(Ok("[80, 443]"), src.take_slice(err.span().clone()));
+    assert_eq!("no value at segment `2` of path `/servers/0/ports/2`", err.to_string());
+    Ok(())
+}
+
+#[test]
+fn malformed_path() -> Result<()> {
+    let mut src = JsonParser::new(SOURCE);
+    let ast = src.parse()?;
+
+    let invalid = |path: &str, reason: &'static str| ErrorKind::InvalidPath { path: path.into(), reason };
+    assert_eq!(&invalid("servers", "a JSON pointer must be empty or start with '/'"), ast.pointer("servers").unwrap_err().kind());
+    assert_eq!(&invalid("/a~2", "'~' must be followed by '0' or '1'"), ast.pointer("/a~2").unwrap_err().kind());
+    assert_eq!(&invalid("servers[x]", "expect an index or a quoted key inside brackets"), ast.get_path("servers[x]").unwrap_err().kind());
+    assert_eq!(&invalid("servers[0", "missing closing ']'"), ast.get_path("servers[0").unwrap_err().kind());
+    assert_eq!(&invalid("servers..host", "empty segment"), ast.get_path("servers..host").unwrap_err().kind());
+    assert_eq!("invalid path `servers[0]host`, expect '.' or '[' between two segments", ast.get_path("servers[0]host").unwrap_err().to_string());
+    Ok(())
+}
@@ -0,0 +1,127 @@
+use std::marker::PhantomData;
+
+use jsode::prelude::*;
+
+#[derive(Deserialize, Serialize, PartialEq, Debug)]
+struct Config<'c, T> {
+    name: String,
+    #[prop = "max-connections"]
+    max_connections: u32,
+    ratio: f64,
+    debug: Option<bool>,
+    hosts: Vec<Host>,
+    _phantom: PhantomData<&'c T>,
+}
+
+#[derive(Deserialize, Serialize, PartialEq, Debug)]
+struct Host {
+    addr: String,
+    port: u16,
+}
+
+#[derive(Serialize)]
+struct Point(i32, i32);
+
+#[derive(Serialize)]
+struct Meters(f32);
+
+#[derive(Serialize)]
+struct Wrap<T> {
+    v: T,
+}
+
+#[derive(Serialize)]
+struct Labeled<'a, L: AsRef<str>, V> where V: Copy {
+    label: &'a L,
+    value: V,
+}
+
+fn sample() -> Config<'static, ()> {
+    Config {
+        name: "api \"v2\"".into(),
+        max_connections: 128,
+        ratio: 0.5,
+        debug: None,
+        hosts: vec![Host { addr: "10.0.0.1".into(), port: 8080 }],
+        _phantom: PhantomData,
+    }
+}
+
+#[test]
+fn serialize_compact() {
+    assert_eq!(
+        r#"{"name":"api \"v2\"","max-connections":128,"ratio":0.5,"debug":null,"hosts":[{"addr":"10.0.0.1","port":8080}]}"#,
+        sample().to_json().unwrap(),
+    );
+}
+
+#[test]
+fn serialize_pretty() {
+    assert_eq!(r#"{
+  "name": "api \"v2\"",
+  "max-connections": 128,
+  "ratio": 0.5,
+  "debug": null,
+  "hosts": [
+    {
+      "addr": "10.0.0.1",
+      "port": 8080
+    }
+  ]
+}"#, sample().to_json_with(&WriterOptions::pretty()).unwrap());
+}
+
+#[test]
+fn serialize_tuple_struct() {
+    assert_eq!("[1,-2]", Point(1, -2).to_json().unwrap());
+    assert_eq!("1.5", Meters(1.5).to_json().unwrap());
+}
+
+#[test]
+fn serialize_generic_struct() {
+    assert_eq!(r#"{"v":1}"#, Wrap { v: 1 }.to_json().unwrap());
+    assert_eq!(r#"{"v":{"v":["a"]}}"#, Wrap { v: Wrap { v: vec!["a"] } }.to_json().unwrap());
+    assert_eq!(r#"{"label":"x","value":0.5}"#, Labeled { label: &"x".to_string(), value: 0.5 }.to_json().unwrap());
+}
+
+#[test]
+fn round_trip() -> Result<()> {
+    let config = sample();
+
+    for options in [WriterOptions::new(), WriterOptions::pretty()] {
+        let json = config.to_json_with(&options).unwrap();
+        let parsed = JsonParser::new(&json).parse()?.parse_into::<Config<'_, ()>>()?;
+        assert_eq!(config, parsed);
+    }
+    Ok(())
+}
+
+#[derive(Deserialize, Serialize, PartialEq, Debug)]
+struct Server {
+    /// doc comment is not a rename
+    #[comment = "shown in the admin page"]
+    name: String,
+    #[prop = "bind-port"]
+    #[comment = "must be greater than 1024"]
+    port: Hex<u16>,
+    timeout: f64,
+}
+
+#[test]
+fn serialize_json5() -> Result<()> {
+    let server = Server { name: "edge".into(), port: Hex(0x1F90), timeout: f64::INFINITY };
+    let options = WriterOptions::json5().with_indent(2).with_quote(Quote::Single).with_trailing_comma(true);
+
+    let json5 = server.to_json_with(&options).unwrap();
+    assert_eq!(r#"{
+  // shown in the admin page
+  name: 'edge',
+  // must be greater than 1024
+  'bind-port': 0x1F90,
+  timeout: Infinity,
+}"#, json5);
+    assert_eq!(server, JsonParser::new(&json5).parse()?.parse_into::<Server>()?);
+
+    assert_eq!(r#"{"name":"edge","bind-port":8080,"timeout":null}"#, server.to_json().unwrap());
+    Ok(())
+}
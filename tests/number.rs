@@ -1,4 +1,4 @@
-use jsode::{JsonIdx, JsonParser, JsonPsr, Result};
+use jsode::prelude::*;
 
 
 #[test]
@@ -229,4 +229,84 @@ fn hotspot() -> Result<()> {
     assert_eq!(Ok(-0x20), out.index("neg_hex").unwrap().parse_into::<i8>());
 
     Ok(())
-}
\ No newline at end of file
+}
+
+#[test]
+fn integer_with_exponent() -> Result<()> {
+    let mut obj = JsonParser::new("{ kilo: 1e3, scaled: 2.50e1, shifted: 150e-1, whole: 7.0, half: 15e-1 }");
+    let out = obj.parse()?;
+
+    assert_eq!(Ok(1000), out.index("kilo").unwrap().parse_into::<u32>());
+    assert_eq!(Ok(1000.0), out.index("kilo").unwrap().parse_into::<f64>());
+    assert_eq!(Ok(25), out.index("scaled").unwrap().parse_into::<i16>());
+    assert_eq!(Ok(15), out.index("shifted").unwrap().parse_into::<u8>());
+    assert_eq!(Ok(7), out.index("whole").unwrap().parse_into::<usize>());
+
+    let err = out.index("half").unwrap().parse_into::<u8>().unwrap_err();
+    assert_eq!(&ErrorKind::InvalidValue { ty: "u8", reason: "`15e-1` has a fractional part".into() }, err.kind());
+    Ok(())
+}
+
+#[test]
+fn integer_out_of_range() -> Result<()> {
+    let mut obj = JsonParser::new("{ min: -0x80, below: -0x81, mega: 1e6, huge: 1e400, tiny: -1e-400, zero: -0 }");
+    let out = obj.parse()?;
+
+    assert_eq!(Ok(i8::MIN), out.index("min").unwrap().parse_into::<i8>());
+    assert_eq!(Ok(-128.0), out.index("min").unwrap().parse_into::<f32>());
+    assert_eq!(Ok(0), out.index("zero").unwrap().parse_into::<u8>());
+
+    let err = out.index("below").unwrap().parse_into::<i8>().unwrap_err();
+    assert_eq!(&ErrorKind::IntegerOverflow("i8"), err.kind());
+    let err = out.index("mega").unwrap().parse_into::<u16>().unwrap_err();
+    assert_eq!(&ErrorKind::IntegerOverflow("u16"), err.kind());
+    let err = out.index("huge").unwrap().parse_into::<u128>().unwrap_err();
+    assert_eq!(&ErrorKind::IntegerOverflow("u128"), err.kind());
+    assert!(out.index("tiny").unwrap().parse_into::<i64>().is_err());
+
+    let err = out.index("min").unwrap().parse_into::<u8>().unwrap_err();
+    assert_eq!(&ErrorKind::TypeMismatch { expected: "u8".into(), found: "negative hexadecimal".into() }, err.kind());
+    Ok(())
+}
+
+#[test]
+fn lossy_integer() -> Result<()> {
+    let src = "{ price: 19.99, debt: -2.5, tiny: 1e-400, big: 256.5, inf: Infinity }";
+    let mut obj = JsonParser::with_options(src, ParserOptions::new().with_lossy_integers(true));
+    let out = obj.parse()?;
+
+    assert_eq!(Ok(19), out.index("price").unwrap().parse_into::<u8>());
+    assert_eq!(Ok(-2), out.index("debt").unwrap().parse_into::<i32>());
+    assert_eq!(Ok(0), out.index("tiny").unwrap().parse_into::<u8>());
+    // truncation never hides an overflow nor a non-finite number
+    assert_eq!(&ErrorKind::IntegerOverflow("u8"), out.index("big").unwrap().parse_into::<u8>().unwrap_err().kind());
+    assert!(out.index("inf").unwrap().parse_into::<i32>().is_err());
+
+    let err = JsonParser::new("[19.99]").parse()?.parse_into::<Vec<u8>>().unwrap_err();
+    assert!(matches!(err.kind(), ErrorKind::InvalidValue { ty: "u8", .. }));
+    Ok(())
+}
+
+#[test]
+fn json_number() -> Result<()> {
+    let src = "[3.14159265358979323846264338327950288, 1e400, -0x80, +1, .5, Infinity, 'x']";
+    let mut obj = JsonParser::new(src);
+    let out = obj.parse()?;
+
+    let numbers = (0..6).map(|index| out.index(index).unwrap().parse_into::<JsonNumber>()).collect::<Result<Vec<_>>>()?;
+    let texts = numbers.iter().map(JsonNumber::as_str).collect::<Vec<_>>();
+    assert_eq!(vec!["3.14159265358979323846264338327950288", "1e400", "-0x80", "+1", ".5", "Infinity"], texts);
+    assert!(numbers[2].is_integer() && numbers[2].is_negative());
+    assert!(!numbers[1].is_integer());
+    assert_eq!(Ok(0.5), numbers[4].parse::<f64>());
+
+    let err = out.index(6).unwrap().parse_into::<JsonNumber>().unwrap_err();
+    assert_eq!(&ErrorKind::TypeMismatch { expected: "JsonNumber".into(), found: "string".into() }, err.kind());
+
+    // kept as it is in JSON5, rewritten into plain JSON otherwise
+    assert_eq!("[3.14159265358979323846264338327950288,1e400,-0x80,+1,.5,Infinity]", numbers.to_json_with(&WriterOptions::json5()).unwrap());
+    assert_eq!("[3.14159265358979323846264338327950288,1e400,-128,1,0.5,null]", numbers.to_json().unwrap());
+
+    assert_eq!("36.6", JsonParser::new("36.6").parse()?.parse_into::<JsonNumber>()?.as_str());
+    Ok(())
+}
@@ -0,0 +1,81 @@
+use std::path::Path;
+
+use jsode::prelude::*;
+
+fn parse_strict(src: &str) -> Result<()> {
+    JsonParser::with_options(src, ParserOptions::strict()).parse().map(|_| ())
+}
+
+fn read_dir(dir: &str) -> impl Iterator<Item = (String, Option<String>)> {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join(dir);
+    std::fs::read_dir(dir).unwrap().map(|entry| {
+        let path = entry.unwrap().path();
+        let name = path.file_name().unwrap().to_string_lossy().to_string();
+        // some files of JSONTestSuite are not even valid UTF-8, so they never reach the parser
+        (name, String::from_utf8(std::fs::read(&path).unwrap()).ok())
+    })
+}
+
+#[test]
+fn accept_all_valid_json() {
+    for (name, src) in read_dir("resources/valid").filter(|(name, _)| name.ends_with(".json")) {
+        let src = src.unwrap();
+        assert!(parse_strict(&src).inspect_err(|err| println!("{name}: {err:?}")).is_ok(), "{name}");
+    }
+}
+
+#[test]
+fn reject_all_invalid_json() {
+    for (name, src) in read_dir("resources/invalid") {
+        let Some(src) = src else { continue; };
+        assert!(parse_strict(&src).is_err(), "{name}");
+    }
+}
+
+#[test]
+fn reject_json5_samples() {
+    assert!(parse_strict(include_str!("../resources/valid/sample3.json5")).is_err());
+    assert!(parse_strict(include_str!("../resources/valid/sample5.json5")).is_err());
+    assert!(parse_strict(include_str!("../resources/valid/sample6.json5")).is_err());
+}
+
+#[test]
+fn json5_is_default_dialect() {
+    assert_eq!(&Dialect::Json5, &JsonParser::new("{}").options().dialect());
+    assert!(JsonParser::new("{ a: [0x1F, +1, .5, 'b',], }").parse().is_ok());
+}
+
+#[test]
+fn strict_error_kind() {
+    let not_allowed = ErrorKind::NotAllowed;
+    let cases = [
+        ("{ 'a': 1 }", not_allowed("single-quoted string")),
+        ("{ a: 1 }", not_allowed("unquoted identifier")),
+        ("[Infinity]", not_allowed("non-finite number")),
+        ("[-Infinity]", not_allowed("non-finite number")),
+        ("[-.5]", not_allowed("leading decimal point")),
+        ("[0x1F]", not_allowed("hexadecimal number")),
+        ("[+1]", not_allowed("leading '+'")),
+        ("[.5]", not_allowed("leading decimal point")),
+        ("[01]", not_allowed("leading zero")),
+        ("[1.]", ErrorKind::InvalidNumber("expect at least one digit after the decimal point".into())),
+        ("[1.0e+]", ErrorKind::InvalidNumber("expect at least one digit in the exponent".into())),
+        ("[1] // comment", not_allowed("comment")),
+        ("[1,]", not_allowed("trailing comma")),
+        ("{\"a\":{},}", not_allowed("trailing comma")),
+        ("[\"\\x41\"]", not_allowed("escape sequence")),
+        ("[\"a\tb\"]", not_allowed("unescaped control character")),
+        ("[1,\u{A0}2]", not_allowed("whitespace character")),
+        ("\u{FEFF}[1]", not_allowed("whitespace character")),
+        ("[\"a\\\nb\"]", not_allowed("escape sequence")),
+        ("{ \\u0061: 1 }", not_allowed("unquoted identifier")),
+    ];
+
+    for (src, expected) in cases {
+        let err = parse_strict(src).expect_err(src);
+        assert_eq!(&expected, err.kind(), "{src}");
+    }
+
+    let err = parse_strict("{ 'a': 1 }").unwrap_err();
+    assert_eq!("single-quoted string is not allowed in strict mode", err.to_string());
+}